@@ -0,0 +1,139 @@
+//! Links against the OpenTimelineIO C bindings and compiles the C++ shim in
+//! `src/shim`, which implements the entry points declared in `src/lib.h`
+//! that the upstream C bindings do not provide.
+//!
+//! The native libraries are located through `OTIO_ROOT` (an install prefix
+//! containing `include/` and `lib/`) or, failing that, through pkg-config.
+//! When neither finds them the crate still builds, but only the opentime part
+//! of the shim, which needs no OpenTimelineIO headers, is compiled, and the
+//! `otio_native` cfg used by the smoke tests is left unset. Setting
+//! `OTIO_REQUIRED` turns that case into a build failure, so that CI cannot
+//! pass without running the smoke tests.
+
+use std::env;
+use std::path::PathBuf;
+
+/// The shim targets this OpenTimelineIO release; it relies on
+/// `Item::enabled`, `ImageSequenceReference` and keyed media references.
+const OTIO_VERSION: &str = "0.15";
+
+/// Shim sources that only use the C structs; these are always compiled.
+const OPENTIME_SHIM_SOURCES: &[&str] = &[
+    "src/shim/exactRationalTime.cpp",
+    "src/shim/rationalTime.cpp",
+    "src/shim/timeRange.cpp",
+    "src/shim/timeRangeSet.cpp",
+    "src/shim/timecode.cpp",
+];
+
+const SHIM_SOURCES: &[&str] = &[
+    "src/shim/composableSearch.cpp",
+    "src/shim/effectsAndMarkers.cpp",
+    "src/shim/enabled.cpp",
+    "src/shim/imageSequenceReference.cpp",
+    "src/shim/mediaLinker.cpp",
+    "src/shim/mediaReferences.cpp",
+    "src/shim/timeEffects.cpp",
+    "src/shim/timelineAlgorithm.cpp",
+    "src/shim/timelineDiff.cpp",
+    "src/shim/timelineIndex.cpp",
+    "src/shim/timelineMerge.cpp",
+    "src/shim/trackAlgorithm.cpp",
+    "src/shim/validation.cpp",
+];
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(otio_native)");
+    println!("cargo:rerun-if-env-changed=OTIO_ROOT");
+    println!("cargo:rerun-if-env-changed=OTIO_REQUIRED");
+    println!("cargo:rerun-if-changed=src/shim");
+
+    let native = find_native_libraries();
+
+    let mut opentime = cc::Build::new();
+    opentime.cpp(true).std("c++17").include("src");
+    if let Some(include_paths) = &native {
+        for path in include_paths {
+            opentime.include(path);
+        }
+        opentime.define("SHIM_OTIO_NATIVE", None);
+    }
+    for source in OPENTIME_SHIM_SOURCES {
+        opentime.file(source);
+    }
+    opentime.compile("copentime_shim");
+
+    let include_paths = match native {
+        Some(include_paths) => include_paths,
+        None if env::var_os("OTIO_REQUIRED").is_some() => {
+            panic!(
+                "OpenTimelineIO {} was not found and OTIO_REQUIRED is set",
+                OTIO_VERSION
+            );
+        }
+        None => {
+            println!(
+                "cargo:warning=OpenTimelineIO {} was not found; set OTIO_ROOT to link the native libraries",
+                OTIO_VERSION
+            );
+            return;
+        }
+    };
+
+    let mut build = cc::Build::new();
+    build
+        .cpp(true)
+        .std("c++17")
+        .include("src")
+        .define("SHIM_OTIO_NATIVE", None);
+    for path in &include_paths {
+        build.include(path);
+    }
+    for source in SHIM_SOURCES {
+        build.file(source);
+    }
+    build.compile("copentimelineio_shim");
+
+    println!("cargo:rustc-cfg=otio_native");
+}
+
+/// Emits the link flags for the C bindings and for OpenTimelineIO itself and
+/// returns the include paths the shim needs.
+fn find_native_libraries() -> Option<Vec<PathBuf>> {
+    if let Some(root) = env::var_os("OTIO_ROOT") {
+        let root = PathBuf::from(root);
+        println!(
+            "cargo:rustc-link-search=native={}",
+            root.join("lib").display()
+        );
+        for lib in ["copentimelineio", "opentimelineio", "opentime"] {
+            println!("cargo:rustc-link-lib={}", lib);
+        }
+        return Some(vec![root.join("include")]);
+    }
+
+    let bindings = probe("copentimelineio", None)?;
+    let otio = probe("opentimelineio", Some(OTIO_VERSION))?;
+    let mut include_paths = Vec::new();
+    for lib in [bindings, otio] {
+        for path in &lib.link_paths {
+            println!("cargo:rustc-link-search=native={}", path.display());
+        }
+        for name in &lib.libs {
+            println!("cargo:rustc-link-lib={}", name);
+        }
+        include_paths.extend(lib.include_paths);
+    }
+    Some(include_paths)
+}
+
+/// Probes a pkg-config package without emitting any link flags, so that a
+/// partial install does not leave the crate half-linked.
+fn probe(name: &str, version: Option<&str>) -> Option<pkg_config::Library> {
+    let mut config = pkg_config::Config::new();
+    config.cargo_metadata(false);
+    if let Some(version) = version {
+        config.atleast_version(version);
+    }
+    config.probe(name).ok()
+}
@@ -15,9 +15,9 @@ ROOT_DIR = os.path.join(os.path.dirname(__file__), "src")
 
 
 def main():
-    rs_path = os.path.join(ROOT_DIR, "lib.rs")
+    rs_path = os.path.join(ROOT_DIR, "bindings.rs")
     header_path = os.path.join(ROOT_DIR, "lib.h")
-    # This runs bindgen -o lib.rs lib.h -- -I/path/to/src
+    # This runs bindgen -o bindings.rs lib.h -- -I/path/to/src
     subprocess.check_call(["bindgen", "-o", rs_path, header_path, "--", "-I{}".format(ROOT_DIR)], cwd=ROOT_DIR)
 
 
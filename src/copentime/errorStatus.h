@@ -19,6 +19,10 @@ typedef enum
     OpenTime_ErrorStatus_Outcome_TIMECODE_RATE_MISMATCH               = 5,
     OpenTime_ErrorStatus_Outcome_NEGATIVE_VALUE                       = 6,
     OpenTime_ErrorStatus_Outcome_INVALID_RATE_FOR_DROP_FRAME_TIMECODE = 7,
+    /* Only set by the shim, so OpenTimeErrorStatus_outcome_to_string has no
+     * name for them. */
+    OpenTime_ErrorStatus_Outcome_OVERFLOW                             = 8,
+    OpenTime_ErrorStatus_Outcome_NOT_REPRESENTABLE                    = 9,
 } OpenTime_ErrorStatus_Outcome_;
 
 typedef int OpenTime_ErrorStatus_Outcome;
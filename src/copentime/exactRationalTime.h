@@ -0,0 +1,79 @@
+#pragma once
+
+#include "errorStatus.h"
+#include "rationalTime.h"
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+/* A fraction kept in lowest terms with a positive denominator. A zero
+ * denominator marks an invalid value. */
+typedef struct {
+    int64_t numerator;
+    int64_t denominator;
+} ExactRational;
+
+/* A time of value frames at rate frames per second. */
+typedef struct {
+    ExactRational value;
+    ExactRational rate;
+} ExactRationalTime;
+
+/* Arithmetic is exact; a result whose numerator or denominator does not fit
+ * in int64 is reported rather than rounded. Failures return an invalid value
+ * and set error_status: a zero denominator or a rate that is not positive
+ * gives INVALID_TIMECODE_RATE, and int64 overflow gives OVERFLOW. */
+
+OTIO_API ExactRational ExactRational_create(
+        int64_t numerator, int64_t denominator, OpenTimeErrorStatus *error_status);
+
+/* Snaps rates within 0.001 of a standard NTSC rate (23.976, 29.97, 47.952,
+ * 59.94, 119.88 or 239.76) to n*1000/1001; any other rate is kept to three
+ * decimal places. */
+OTIO_API ExactRational ExactRational_from_rate(double rate);
+
+OTIO_API double ExactRational_to_double(ExactRational self);
+
+OTIO_API bool ExactRational_is_ntsc_rate(ExactRational self);
+
+OTIO_API ExactRationalTime
+ExactRationalTime_create(ExactRational value, ExactRational rate);
+
+OTIO_API ExactRationalTime ExactRationalTime_from_frames(int64_t frames, ExactRational rate);
+
+/* The rate is snapped as by ExactRational_from_rate; a value that is not
+ * finite or has no exact fraction with a denominator up to 2^32 gives
+ * NOT_REPRESENTABLE. */
+OTIO_API ExactRationalTime ExactRationalTime_from_rational_time(
+        RationalTime rational_time, OpenTimeErrorStatus *error_status);
+
+OTIO_API RationalTime ExactRationalTime_to_rational_time(ExactRationalTime self);
+
+OTIO_API bool ExactRationalTime_is_invalid_time(ExactRationalTime self);
+
+/* Returns an invalid time if the rescaled value overflows. */
+OTIO_API ExactRationalTime
+ExactRationalTime_rescaled_to(ExactRationalTime self, ExactRational new_rate);
+
+OTIO_API ExactRational ExactRationalTime_to_seconds(ExactRationalTime self);
+
+/* Like RationalTime, the result is at the higher of the two rates. */
+OTIO_API ExactRationalTime ExactRationalTime_add(
+        ExactRationalTime lhs, ExactRationalTime rhs, OpenTimeErrorStatus *error_status);
+
+OTIO_API ExactRationalTime ExactRationalTime_subtract(
+        ExactRationalTime lhs, ExactRationalTime rhs, OpenTimeErrorStatus *error_status);
+
+/* Returns -1, 0 or 1 as lhs is earlier than, equal to or later than rhs;
+ * invalid times sort before valid ones. */
+OTIO_API int ExactRationalTime_compare(ExactRationalTime lhs, ExactRationalTime rhs);
+
+OTIO_API bool ExactRationalTime_equal(ExactRationalTime lhs, ExactRationalTime rhs);
+
+OTIO_API bool
+ExactRationalTime_not_equal(ExactRationalTime lhs, ExactRationalTime rhs);
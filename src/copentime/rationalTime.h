@@ -2,6 +2,7 @@
 
 #include "copentime/errorStatus.h"
 #include <stdbool.h>
+#include <stdint.h>
 
 #ifdef __cplusplus
 # define OTIO_API extern "C"
@@ -21,6 +22,24 @@ typedef enum {
 } OpenTime_IsDropFrameRate_;
 typedef int OpenTime_IsDropFrameRate;
 
+typedef enum {
+    OpenTime_RoundingPolicy_Floor = 0,
+    OpenTime_RoundingPolicy_Ceil = 1,
+    OpenTime_RoundingPolicy_Nearest = 2,
+    OpenTime_RoundingPolicy_NearestEven = 3,
+} OpenTime_RoundingPolicy_;
+typedef int OpenTime_RoundingPolicy;
+
+/* Film gauges for feet+frames: 16 frames per foot for 35mm 4-perf, 32 for
+ * 35mm 2-perf and 40 for 16mm. 35mm 3-perf is left out, since its 21 1/3
+ * frames per foot has no single feet+frames convention. */
+typedef enum {
+    OpenTime_FilmGauge_35mm_4Perf = 0,
+    OpenTime_FilmGauge_35mm_2Perf = 1,
+    OpenTime_FilmGauge_16mm = 2,
+} OpenTime_FilmGauge_;
+typedef int OpenTime_FilmGauge;
+
 OTIO_API RationalTime RationalTime_create(double value, double rate);
 
 OTIO_API bool RationalTime_is_invalid_time(RationalTime self);
@@ -58,10 +77,35 @@ OTIO_API RationalTime RationalTime_from_timecode(
 OTIO_API RationalTime RationalTime_from_time_string(
         const char *time_string, double rate, OpenTimeErrorStatus *error_status);
 
+/* Parses [-]FEET+FF at rate; malformed strings and frames of a foot or more
+ * give INVALID_TIME_STRING. */
+OTIO_API RationalTime RationalTime_from_feet_frames(
+        const char *feet_frames,
+        double rate,
+        OpenTime_FilmGauge gauge,
+        OpenTimeErrorStatus *error_status);
+
+OTIO_API RationalTime RationalTime_from_samples(int64_t samples, double sample_rate);
+
 OTIO_API int RationalTime_to_frames(RationalTime self);
 
 OTIO_API int RationalTime_to_frames_with_rate(RationalTime self, double rate);
 
+/* The rounded variants treat values within 1e-9 of a whole frame as whole,
+ * so rescaling noise does not push them to the next frame. Frame counts
+ * saturate at the int64 limits. */
+OTIO_API int64_t RationalTime_to_frames_rounded(
+        RationalTime self, OpenTime_RoundingPolicy rounding_policy);
+
+OTIO_API int64_t RationalTime_to_frames_rounded_with_rate(
+        RationalTime self, double rate, OpenTime_RoundingPolicy rounding_policy);
+
+OTIO_API RationalTime RationalTime_rescaled_to_rounded(
+        RationalTime self, double new_rate, OpenTime_RoundingPolicy rounding_policy);
+
+OTIO_API RationalTime RationalTime_snap_to_frame(
+        RationalTime self, OpenTime_RoundingPolicy rounding_policy);
+
 OTIO_API double RationalTime_to_seconds(RationalTime self);
 
 OTIO_API const char *RationalTime_to_timecode(
@@ -75,12 +119,37 @@ OTIO_API const char *RationalTime_to_timecode_auto(
 
 OTIO_API const char *RationalTime_to_time_string(RationalTime self);
 
+/* Formats the nearest whole frame as FEET+FF; the caller frees the returned
+ * string. */
+OTIO_API const char *
+RationalTime_to_feet_frames(RationalTime self, OpenTime_FilmGauge gauge);
+
+/* The sample count is the time at sample_rate, rounded by rounding_policy. */
+OTIO_API int64_t RationalTime_to_samples(
+        RationalTime self, double sample_rate, OpenTime_RoundingPolicy rounding_policy);
+
 OTIO_API RationalTime RationalTime_add(RationalTime lhs, RationalTime rhs);
 
 OTIO_API RationalTime RationalTime_subtract(RationalTime lhs, RationalTime rhs);
 
+/* Deprecated: returns a RationalTime rather than an ordering, and is kept
+ * only for compatibility. Use RationalTime_cmp, or RationalTime_total_order
+ * where NaN must be ordered. */
 OTIO_API RationalTime RationalTime_compare(RationalTime lhs, RationalTime rhs);
 
+/* Returns -1, 0 or 1 as lhs is earlier than, equal to or later than rhs,
+ * comparing seconds so that times at different rates compare; 0 when either
+ * is NaN. */
+OTIO_API int RationalTime_cmp(RationalTime lhs, RationalTime rhs);
+
+/* Like RationalTime_cmp, but total: every invalid time (see
+ * RationalTime_is_invalid_time) is equal to every other and sorts first. */
+OTIO_API int RationalTime_total_order(RationalTime lhs, RationalTime rhs);
+
+/* A hash consistent with RationalTime_total_order, so 1 at 24 and 2 at 48
+ * hash alike. */
+OTIO_API uint64_t RationalTime_hash(RationalTime self);
+
 OTIO_API bool RationalTime_equal(RationalTime lhs, RationalTime rhs);
 
 OTIO_API bool RationalTime_not_equal(RationalTime lhs, RationalTime rhs);
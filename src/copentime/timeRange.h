@@ -2,6 +2,7 @@
 
 #include "rationalTime.h"
 #include <stdbool.h>
+#include <stdint.h>
 
 #ifdef __cplusplus
 # define OTIO_API extern "C"
@@ -58,3 +59,42 @@ OTIO_API bool TimeRange_not_equal(TimeRange lhs, TimeRange rhs);
 
 OTIO_API TimeRange TimeRange_range_from_start_end_time(
         RationalTime start_time, RationalTime end_time_exclusive);
+
+/* Rounds the start and the exclusive end to whole frames of the start
+ * time's rate, each with its own policy; the duration follows from them. */
+OTIO_API TimeRange TimeRange_snap_to_frame(
+        TimeRange self,
+        OpenTime_RoundingPolicy start_rounding_policy,
+        OpenTime_RoundingPolicy end_rounding_policy);
+
+/* Iterates the whole frames at rate that start within the range, as exact
+ * RationalTimes at rate. The range may have another rate. Iterators are
+ * owned by the caller, who destroys them. */
+typedef struct TimeRangeFrameIterator TimeRangeFrameIterator;
+
+/* A rate that is not positive gives INVALID_TIMECODE_RATE and NULL. */
+OTIO_API TimeRangeFrameIterator *TimeRange_frames(
+        TimeRange self, double rate, bool reverse, OpenTimeErrorStatus *error_status);
+
+/* Yields every step-th frame, starting from the first frame; reverse yields
+ * the same frames last first. A step that is not positive gives
+ * NEGATIVE_VALUE and NULL. */
+OTIO_API TimeRangeFrameIterator *TimeRange_frames_step_by(
+        TimeRange self,
+        double rate,
+        int64_t step,
+        bool reverse,
+        OpenTimeErrorStatus *error_status);
+
+/* Returns false once the frames are exhausted. */
+OTIO_API bool TimeRangeFrameIterator_next(
+        TimeRangeFrameIterator *iter, RationalTime *frame_time);
+
+/* Takes a frame from the other end from TimeRangeFrameIterator_next; the two
+ * meet without repeating a frame. */
+OTIO_API bool TimeRangeFrameIterator_next_back(
+        TimeRangeFrameIterator *iter, RationalTime *frame_time);
+
+OTIO_API int64_t TimeRangeFrameIterator_remaining(TimeRangeFrameIterator *iter);
+
+OTIO_API void TimeRangeFrameIterator_destroy(TimeRangeFrameIterator *self);
@@ -0,0 +1,69 @@
+#pragma once
+
+#include "optionalOpenTime.h"
+#include "rationalTime.h"
+#include "timeRange.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+/* A set of disjoint ranges, kept sorted with touching ranges merged. The
+ * set takes the rate of the first range inserted and rescales later ones to
+ * it; a result combining two sets takes lhs's rate, or rhs's if lhs is
+ * empty. Ranges and times with a rate that is not positive are ignored by
+ * insert and remove and are never contained or overlapped. Sets returned by
+ * the functions below are owned by the caller, who destroys them. */
+typedef struct TimeRangeSet TimeRangeSet;
+
+OTIO_API TimeRangeSet *TimeRangeSet_create();
+
+OTIO_API void TimeRangeSet_destroy(TimeRangeSet *self);
+
+OTIO_API TimeRangeSet *TimeRangeSet_clone(TimeRangeSet *self);
+
+OTIO_API void TimeRangeSet_insert(TimeRangeSet *self, TimeRange time_range);
+
+OTIO_API void TimeRangeSet_remove(TimeRangeSet *self, TimeRange time_range);
+
+OTIO_API void TimeRangeSet_clear(TimeRangeSet *self);
+
+OTIO_API int TimeRangeSet_size(TimeRangeSet *self);
+
+OTIO_API bool TimeRangeSet_empty(TimeRangeSet *self);
+
+/* The ranges in start order. */
+OTIO_API TimeRange TimeRangeSet_at(TimeRangeSet *self, int pos);
+
+OTIO_API OptionalTimeRange TimeRangeSet_bounds(TimeRangeSet *self);
+
+/* The total duration of the ranges. */
+OTIO_API RationalTime TimeRangeSet_coverage(TimeRangeSet *self);
+
+OTIO_API bool
+TimeRangeSet_contains_rational_time(TimeRangeSet *self, RationalTime other);
+
+OTIO_API bool
+TimeRangeSet_contains_time_range(TimeRangeSet *self, TimeRange other);
+
+OTIO_API bool
+TimeRangeSet_overlaps_time_range(TimeRangeSet *self, TimeRange other);
+
+OTIO_API TimeRangeSet *
+TimeRangeSet_union(TimeRangeSet *lhs, TimeRangeSet *rhs);
+
+OTIO_API TimeRangeSet *
+TimeRangeSet_intersection(TimeRangeSet *lhs, TimeRangeSet *rhs);
+
+OTIO_API TimeRangeSet *
+TimeRangeSet_difference(TimeRangeSet *lhs, TimeRangeSet *rhs);
+
+/* The parts of bounds not in the set, at the rate of bounds; for a track's
+ * clips, these are its gaps. */
+OTIO_API TimeRangeSet *
+TimeRangeSet_complement_within(TimeRangeSet *self, TimeRange bounds);
+
+OTIO_API bool TimeRangeSet_equal(TimeRangeSet *lhs, TimeRangeSet *rhs);
@@ -0,0 +1,89 @@
+#pragma once
+
+#include "errorStatus.h"
+#include "rationalTime.h"
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+/* A timecode at rate frames per second. The fields hold the magnitude and
+ * negative its sign. drop_frame is the resolved drop-frame choice; only
+ * 29.97 and 59.94 (n*1000/1001 rates with n a multiple of 30) allow it.
+ * Functions that take an OpenTime_IsDropFrameRate resolve InferFromRate to
+ * drop frame for exactly those rates. */
+typedef struct {
+    int hours;
+    int minutes;
+    int seconds;
+    int frames;
+    bool drop_frame;
+    bool negative;
+    double rate;
+} Timecode;
+
+/* Fields out of range, including frames a drop-frame timecode skips, give
+ * INVALID_TIMECODE_STRING; a rate that is not positive gives
+ * INVALID_TIMECODE_RATE, and forcing drop frame at a rate without it gives
+ * INVALID_RATE_FOR_DROP_FRAME_TIMECODE. */
+OTIO_API Timecode Timecode_create(
+        int hours,
+        int minutes,
+        int seconds,
+        int frames,
+        double rate,
+        OpenTime_IsDropFrameRate drop_frame,
+        OpenTimeErrorStatus *error_status);
+
+/* Parses [-]HH:MM:SS:FF; a ';' before the frames marks drop frame. */
+OTIO_API Timecode Timecode_from_string(
+        const char *timecode, double rate, OpenTimeErrorStatus *error_status);
+
+/* The returned string is owned by the caller, who frees it. */
+OTIO_API const char *Timecode_to_string(Timecode self);
+
+/* A timecode whose hours do not fit in an int, or a sum of frames that does
+ * not fit in int64, gives OVERFLOW here and in the arithmetic below. */
+OTIO_API Timecode Timecode_from_frames(
+        int64_t frames,
+        double rate,
+        OpenTime_IsDropFrameRate drop_frame,
+        OpenTimeErrorStatus *error_status);
+
+OTIO_API int64_t Timecode_to_frames(Timecode self);
+
+/* rational_time is rounded to the nearest frame at rate. */
+OTIO_API Timecode Timecode_from_rational_time(
+        RationalTime rational_time,
+        double rate,
+        OpenTime_IsDropFrameRate drop_frame,
+        OpenTimeErrorStatus *error_status);
+
+OTIO_API RationalTime Timecode_to_rational_time(Timecode self);
+
+OTIO_API Timecode
+Timecode_add_frames(Timecode self, int64_t frames, OpenTimeErrorStatus *error_status);
+
+/* Both timecodes must have the same rate, or TIMECODE_RATE_MISMATCH is
+ * reported; the result keeps lhs's drop-frame choice. */
+OTIO_API Timecode
+Timecode_add(Timecode lhs, Timecode rhs, OpenTimeErrorStatus *error_status);
+
+OTIO_API Timecode Timecode_subtract(
+        Timecode lhs, Timecode rhs, OpenTimeErrorStatus *error_status);
+
+/* Wraps into [00:00:00:00, 24:00:00:00), so negative timecodes count back
+ * from midnight. */
+OTIO_API Timecode Timecode_wrapped_to_24_hours(Timecode self);
+
+/* Compares the times the timecodes stand for, so different rates compare
+ * by seconds. */
+OTIO_API int Timecode_compare(Timecode lhs, Timecode rhs);
+
+OTIO_API bool Timecode_equal(Timecode lhs, Timecode rhs);
+
+OTIO_API bool Timecode_not_equal(Timecode lhs, Timecode rhs);
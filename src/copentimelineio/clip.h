@@ -19,6 +19,16 @@
 
 typedef struct Clip Clip;
 
+/* The key OpenTimelineIO uses for a clip's only reference, with C linkage
+ * from C and C++ alike. */
+#ifdef __cplusplus
+extern "C" {
+#endif
+extern const char *Clip_default_media_key;
+#ifdef __cplusplus
+}
+#endif
+
 OTIO_API Clip *Clip_create(
         const char *name,
         MediaReference *media_reference,
@@ -26,11 +36,52 @@ OTIO_API Clip *Clip_create(
         AnyDictionary *metadata);
 OTIO_API void Clip_set_media_reference(Clip *self, MediaReference *media_reference);
 OTIO_API MediaReference *Clip_media_reference(Clip *self);
+/* Keys are returned malloc'd and the caller frees them; references are
+ * borrowed from the clip. Keys are visited in sorted order. Setting a key
+ * hands the reference to the clip and releases the one it replaces; the
+ * active key cannot be removed. */
+OTIO_API const char *Clip_active_media_reference_key(Clip *self);
+OTIO_API bool Clip_set_active_media_reference_key(
+        Clip *self, const char *new_active_key, OTIOErrorStatus *error_status);
+OTIO_API int Clip_media_reference_count(Clip *self);
+OTIO_API const char *Clip_media_reference_key_at(Clip *self, int index);
+OTIO_API MediaReference *
+Clip_media_reference_for_key(Clip *self, const char *key);
+OTIO_API bool Clip_set_media_reference_for_key(
+        Clip *self,
+        const char *key,
+        MediaReference *media_reference,
+        OTIOErrorStatus *error_status);
+OTIO_API bool Clip_remove_media_reference(
+        Clip *self, const char *key, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Clip_available_range(Clip *self, OTIOErrorStatus *error_status);
 OTIO_API OptionalTimeRange Clip_source_range(Clip *self);
 OTIO_API void Clip_set_source_range(Clip *self, OptionalTimeRange source_range);
 OTIO_API EffectRetainerVector *Clip_effects(Clip *self);
 OTIO_API MarkerRetainerVector *Clip_markers(Clip *self);
+OTIO_API bool Clip_append_effect(
+        Clip *self, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API bool Clip_insert_effect(
+        Clip *self, int index, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API Effect *
+Clip_remove_effect(Clip *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Clip_move_effect(
+        Clip *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Clip_clear_effects(Clip *self);
+OTIO_API int Clip_retain_effects(
+        Clip *self, EffectPredicate predicate, void *user_data);
+OTIO_API bool Clip_append_marker(
+        Clip *self, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API bool Clip_insert_marker(
+        Clip *self, int index, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API Marker *
+Clip_remove_marker(Clip *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Clip_move_marker(
+        Clip *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Clip_clear_markers(Clip *self);
+OTIO_API int Clip_retain_markers(
+        Clip *self, MarkerPredicate predicate, void *user_data);
+OTIO_API void Clip_sort_markers(Clip *self);
 OTIO_API RationalTime Clip_duration(Clip *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Clip_trimmed_range(Clip *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Clip_visible_range(Clip *self, OTIOErrorStatus *error_status);
@@ -47,8 +98,22 @@ OTIO_API TimeRange Clip_transformed_time_range(
         TimeRange time_range,
         Item *to_item,
         OTIOErrorStatus *error_status);
+/* Time-effect-aware mapping between the clip's parent and its media. The
+ * LinearTimeWarp and FreezeFrame effects on the clip are combined into one
+ * time scalar that scales the offset from the start of the clip. A negative
+ * scalar plays the consumed media backwards from its last frame, and a
+ * scalar of zero holds the first frame, which has no inverse. On error
+ * Clip_media_time_for_parent_time returns zero at parent_time's rate. */
+OTIO_API RationalTime Clip_media_time_for_parent_time(
+        Clip *self, RationalTime parent_time, OTIOErrorStatus *error_status);
+OTIO_API OptionalRationalTime Clip_parent_time_for_media_time(
+        Clip *self, RationalTime media_time, OTIOErrorStatus *error_status);
+OTIO_API TimeRange
+Clip_consumed_media_range(Clip *self, OTIOErrorStatus *error_status);
 OTIO_API bool Clip_visible(Clip *self);
 OTIO_API bool Clip_overlapping(Clip *self);
+OTIO_API bool Clip_enabled(Clip *self);
+OTIO_API void Clip_set_enabled(Clip *self, bool enabled);
 OTIO_API Composition *Clip_parent(Clip *self);
 OTIO_API const char *Clip_name(Clip *self);
 OTIO_API AnyDictionary *Clip_metadata(Clip *self);
@@ -0,0 +1,37 @@
+#pragma once
+
+#include "composable.h"
+#include "copentime/optionalOpenTime.h"
+#include "errorStatus.h"
+#include "serializableObject.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+typedef bool (*ComposablePredicate)(Composable *child, void *user_data);
+typedef struct ComposableSearch ComposableSearch;
+
+/* Lazily walks the children of a Timeline, Stack, Track, Composition or
+ * SerializableCollection in pre-order. schema_name matches the child's own
+ * schema or one of its bases ("Composition", "Item", "Composable"); NULL
+ * matches everything. search_range is in the root's own time, before any
+ * Timeline global_start_time. The root must outlive the search, and the
+ * returned children are borrowed from their parents. On failure
+ * ComposableSearch_create sets error_status and returns NULL. */
+
+OTIO_API ComposableSearch *ComposableSearch_create(
+        OTIOSerializableObject *root,
+        const char *schema_name,
+        OptionalTimeRange search_range,
+        bool shallow_search,
+        ComposablePredicate predicate,
+        void *user_data,
+        OTIOErrorStatus *error_status);
+OTIO_API Composable *
+ComposableSearch_next(ComposableSearch *self, OTIOErrorStatus *error_status);
+OTIO_API bool ComposableSearch_done(ComposableSearch *self);
+OTIO_API void ComposableSearch_destroy(ComposableSearch *self);
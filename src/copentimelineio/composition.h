@@ -74,10 +74,35 @@ OTIO_API MapComposableTimeRange *Composition_range_of_all_children(
 
 OTIO_API bool Composition_visible(Composition *self);
 OTIO_API bool Composition_overlapping(Composition *self);
+OTIO_API bool Composition_enabled(Composition *self);
+OTIO_API void Composition_set_enabled(Composition *self, bool enabled);
 OTIO_API OptionalTimeRange Composition_source_range(Composition *self);
 OTIO_API void Composition_set_source_range(Composition *self, OptionalTimeRange source_range);
 OTIO_API EffectRetainerVector *Composition_effects(Composition *self);
 OTIO_API MarkerRetainerVector *Composition_markers(Composition *self);
+OTIO_API bool Composition_append_effect(
+        Composition *self, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API bool Composition_insert_effect(
+        Composition *self, int index, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API Effect *
+Composition_remove_effect(Composition *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Composition_move_effect(
+        Composition *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Composition_clear_effects(Composition *self);
+OTIO_API int Composition_retain_effects(
+        Composition *self, EffectPredicate predicate, void *user_data);
+OTIO_API bool Composition_append_marker(
+        Composition *self, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API bool Composition_insert_marker(
+        Composition *self, int index, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API Marker *
+Composition_remove_marker(Composition *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Composition_move_marker(
+        Composition *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Composition_clear_markers(Composition *self);
+OTIO_API int Composition_retain_markers(
+        Composition *self, MarkerPredicate predicate, void *user_data);
+OTIO_API void Composition_sort_markers(Composition *self);
 OTIO_API RationalTime
 Composition_duration(Composition *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Composition_available_range(
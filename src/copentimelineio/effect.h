@@ -2,6 +2,7 @@
 
 #include "anyDictionary.h"
 #include "serializableObject.h"
+#include <stdbool.h>
 
 #ifdef __cplusplus
 # define OTIO_API extern "C"
@@ -11,6 +12,7 @@
 
 typedef struct RetainerEffect RetainerEffect;
 typedef struct Effect Effect;
+typedef bool (*EffectPredicate)(Effect *effect, void *user_data);
 
 OTIO_API RetainerEffect *RetainerEffect_create(Effect *obj);
 
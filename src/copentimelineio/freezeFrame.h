@@ -1,6 +1,7 @@
 #pragma once
 
 #include "anyDictionary.h"
+#include "copentime/rationalTime.h"
 #include "errorStatus.h"
 #include <stdbool.h>
 
@@ -18,6 +19,9 @@ OTIO_API double FreezeFrame_time_scalar(FreezeFrame *self);
 
 OTIO_API void FreezeFrame_set_time_scalar(FreezeFrame *self, double time_scalar);
 
+OTIO_API RationalTime
+FreezeFrame_apply_to_time(FreezeFrame *self, RationalTime time_from_start);
+
 OTIO_API const char *FreezeFrame_effect_name(FreezeFrame *self);
 
 OTIO_API void
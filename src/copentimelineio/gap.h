@@ -37,6 +37,10 @@ OTIO_API bool Gap_visible(Gap *self);
 
 OTIO_API bool Gap_overlapping(Gap *self);
 
+OTIO_API bool Gap_enabled(Gap *self);
+
+OTIO_API void Gap_set_enabled(Gap *self, bool enabled);
+
 OTIO_API OptionalTimeRange Gap_source_range(Gap *self);
 
 OTIO_API void Gap_set_source_range(Gap *self, OptionalTimeRange source_range);
@@ -45,6 +49,42 @@ OTIO_API EffectRetainerVector *Gap_effects(Gap *self);
 
 OTIO_API MarkerRetainerVector *Gap_markers(Gap *self);
 
+OTIO_API bool Gap_append_effect(
+        Gap *self, Effect *effect, OTIOErrorStatus *error_status);
+
+OTIO_API bool Gap_insert_effect(
+        Gap *self, int index, Effect *effect, OTIOErrorStatus *error_status);
+
+OTIO_API Effect *
+Gap_remove_effect(Gap *self, int index, OTIOErrorStatus *error_status);
+
+OTIO_API bool Gap_move_effect(
+        Gap *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+
+OTIO_API void Gap_clear_effects(Gap *self);
+
+OTIO_API int Gap_retain_effects(
+        Gap *self, EffectPredicate predicate, void *user_data);
+
+OTIO_API bool Gap_append_marker(
+        Gap *self, Marker *marker, OTIOErrorStatus *error_status);
+
+OTIO_API bool Gap_insert_marker(
+        Gap *self, int index, Marker *marker, OTIOErrorStatus *error_status);
+
+OTIO_API Marker *
+Gap_remove_marker(Gap *self, int index, OTIOErrorStatus *error_status);
+
+OTIO_API bool Gap_move_marker(
+        Gap *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+
+OTIO_API void Gap_clear_markers(Gap *self);
+
+OTIO_API int Gap_retain_markers(
+        Gap *self, MarkerPredicate predicate, void *user_data);
+
+OTIO_API void Gap_sort_markers(Gap *self);
+
 OTIO_API RationalTime Gap_duration(Gap *self, OTIOErrorStatus *error_status);
 
 OTIO_API TimeRange Gap_available_range(Gap *self, OTIOErrorStatus *error_status);
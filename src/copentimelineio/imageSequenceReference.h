@@ -0,0 +1,106 @@
+#pragma once
+
+#include "copentime/timeRange.h"
+#include "copentime/optionalOpenTime.h"
+#include "anyDictionary.h"
+#include "errorStatus.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+typedef enum {
+    OTIO_ImageSequenceReference_MissingFramePolicy_error = 0,
+    OTIO_ImageSequenceReference_MissingFramePolicy_hold = 1,
+    OTIO_ImageSequenceReference_MissingFramePolicy_black = 2,
+} OTIO_ImageSequenceReference_MissingFramePolicy_;
+typedef int OTIO_ImageSequenceReference_MissingFramePolicy;
+
+/* Strings returned by the accessors below are malloc'd; the caller frees
+ * them. */
+typedef struct ImageSequenceReference ImageSequenceReference;
+
+OTIO_API ImageSequenceReference *ImageSequenceReference_create(
+        const char *target_url_base,
+        const char *name_prefix,
+        const char *name_suffix,
+        int start_frame,
+        int frame_step,
+        double rate,
+        int frame_zero_padding,
+        OTIO_ImageSequenceReference_MissingFramePolicy missing_frame_policy,
+        OptionalTimeRange available_range,
+        AnyDictionary *metadata);
+OTIO_API const char *
+ImageSequenceReference_target_url_base(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_target_url_base(
+        ImageSequenceReference *self, const char *target_url_base);
+OTIO_API const char *
+ImageSequenceReference_name_prefix(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_name_prefix(
+        ImageSequenceReference *self, const char *name_prefix);
+OTIO_API const char *
+ImageSequenceReference_name_suffix(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_name_suffix(
+        ImageSequenceReference *self, const char *name_suffix);
+OTIO_API int ImageSequenceReference_start_frame(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_start_frame(
+        ImageSequenceReference *self, int start_frame);
+OTIO_API int ImageSequenceReference_frame_step(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_frame_step(
+        ImageSequenceReference *self, int frame_step);
+OTIO_API double ImageSequenceReference_rate(ImageSequenceReference *self);
+OTIO_API void
+ImageSequenceReference_set_rate(ImageSequenceReference *self, double rate);
+OTIO_API int
+ImageSequenceReference_frame_zero_padding(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_frame_zero_padding(
+        ImageSequenceReference *self, int frame_zero_padding);
+OTIO_API OTIO_ImageSequenceReference_MissingFramePolicy
+ImageSequenceReference_missing_frame_policy(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_missing_frame_policy(
+        ImageSequenceReference *self,
+        OTIO_ImageSequenceReference_MissingFramePolicy missing_frame_policy);
+OTIO_API int ImageSequenceReference_end_frame(ImageSequenceReference *self);
+OTIO_API int ImageSequenceReference_number_of_images_in_sequence(
+        ImageSequenceReference *self);
+OTIO_API int ImageSequenceReference_frame_for_time(
+        ImageSequenceReference *self,
+        RationalTime rational_time,
+        OTIOErrorStatus *error_status);
+OTIO_API const char *ImageSequenceReference_target_url_for_image_number(
+        ImageSequenceReference *self,
+        int image_number,
+        OTIOErrorStatus *error_status);
+OTIO_API RationalTime ImageSequenceReference_presentation_time_for_image_number(
+        ImageSequenceReference *self,
+        int image_number,
+        OTIOErrorStatus *error_status);
+OTIO_API OptionalTimeRange
+ImageSequenceReference_available_range(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_available_range(
+        ImageSequenceReference *self, OptionalTimeRange available_range);
+OTIO_API bool
+ImageSequenceReference_is_missing_reference(ImageSequenceReference *self);
+OTIO_API const char *ImageSequenceReference_name(ImageSequenceReference *self);
+OTIO_API void ImageSequenceReference_set_name(
+        ImageSequenceReference *self, const char *name);
+OTIO_API AnyDictionary *
+ImageSequenceReference_metadata(ImageSequenceReference *self);
+OTIO_API bool ImageSequenceReference_to_json_file(
+        ImageSequenceReference *self,
+        const char *file_name,
+        OTIOErrorStatus *error_status,
+        int indent);
+OTIO_API const char *ImageSequenceReference_to_json_string(
+        ImageSequenceReference *self, OTIOErrorStatus *error_status, int indent);
+OTIO_API bool ImageSequenceReference_is_equivalent_to(
+        ImageSequenceReference *self, OTIOSerializableObject *other);
+OTIO_API ImageSequenceReference *ImageSequenceReference_clone(
+        ImageSequenceReference *self, OTIOErrorStatus *error_status);
+OTIO_API const char *
+ImageSequenceReference_schema_name(ImageSequenceReference *self);
+OTIO_API int ImageSequenceReference_schema_version(ImageSequenceReference *self);
@@ -30,10 +30,41 @@ Item_create(
         MarkerVector *markers);
 OTIO_API bool Item_visible(Item *self);
 OTIO_API bool Item_overlapping(Item *self);
+OTIO_API bool Item_enabled(Item *self);
+OTIO_API void Item_set_enabled(Item *self, bool enabled);
 OTIO_API OptionalTimeRange Item_source_range(Item *self);
 OTIO_API void Item_set_source_range(Item *self, OptionalTimeRange source_range);
 OTIO_API EffectRetainerVector *Item_effects(Item *self);
 OTIO_API MarkerRetainerVector *Item_markers(Item *self);
+/* The item takes ownership of appended and inserted effects and markers.
+ * The remove functions hand the removed object back to the caller, who then
+ * owns it, and the retain functions return how many objects were removed.
+ * Only the functions that take an index or an object can fail; clear,
+ * retain (with a non-NULL predicate) and sort cannot, so they take no error
+ * status. Clip, Gap, Composition, Stack and Track have the same functions. */
+OTIO_API bool Item_append_effect(
+        Item *self, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API bool Item_insert_effect(
+        Item *self, int index, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API Effect *
+Item_remove_effect(Item *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Item_move_effect(
+        Item *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Item_clear_effects(Item *self);
+OTIO_API int Item_retain_effects(
+        Item *self, EffectPredicate predicate, void *user_data);
+OTIO_API bool Item_append_marker(
+        Item *self, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API bool Item_insert_marker(
+        Item *self, int index, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API Marker *
+Item_remove_marker(Item *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Item_move_marker(
+        Item *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Item_clear_markers(Item *self);
+OTIO_API int Item_retain_markers(
+        Item *self, MarkerPredicate predicate, void *user_data);
+OTIO_API void Item_sort_markers(Item *self);
 OTIO_API RationalTime Item_duration(Item *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Item_available_range(Item *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Item_trimmed_range(Item *self, OTIOErrorStatus *error_status);
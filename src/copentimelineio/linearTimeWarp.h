@@ -1,6 +1,8 @@
 #pragma once
 
 #include "anyDictionary.h"
+#include "copentime/optionalOpenTime.h"
+#include "copentime/rationalTime.h"
 #include "errorStatus.h"
 #include <stdbool.h>
 
@@ -23,6 +25,12 @@ OTIO_API double LinearTimeWarp_time_scalar(LinearTimeWarp *self);
 OTIO_API void
 LinearTimeWarp_set_time_scalar(LinearTimeWarp *self, double time_scalar);
 
+OTIO_API RationalTime LinearTimeWarp_apply_to_time(
+        LinearTimeWarp *self, RationalTime time_from_start);
+
+OTIO_API OptionalRationalTime LinearTimeWarp_apply_inverse_to_time(
+        LinearTimeWarp *self, RationalTime media_time_from_start);
+
 OTIO_API const char *LinearTimeWarp_effect_name(LinearTimeWarp *self);
 
 OTIO_API void LinearTimeWarp_set_effect_name(
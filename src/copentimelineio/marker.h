@@ -4,6 +4,7 @@
 #include "copentime/optionalOpenTime.h"
 #include "anyDictionary.h"
 #include "errorStatus.h"
+#include <stdbool.h>
 
 #ifdef __cplusplus
 # define OTIO_API extern "C"
@@ -24,6 +25,7 @@ OTIO_API const char *MarkerColor_black;
 OTIO_API const char *MarkerColor_white;
 typedef struct RetainerMarker RetainerMarker;
 typedef struct Marker Marker;
+typedef bool (*MarkerPredicate)(Marker *marker, void *user_data);
 
 OTIO_API RetainerMarker *RetainerMarker_create(Marker *obj);
 OTIO_API Marker *RetainerMarker_take_value(RetainerMarker *self);
@@ -0,0 +1,75 @@
+#pragma once
+
+#include "anyDictionary.h"
+#include "errorStatus.h"
+#include "mediaReference.h"
+#include "serializableObject.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+typedef enum
+{
+    OTIO_MediaLinker_Result_KEEP       = 0,
+    OTIO_MediaLinker_Result_LINKED     = 1,
+    OTIO_MediaLinker_Result_UNRESOLVED = 2,
+} OTIO_MediaLinker_Result_;
+typedef int OTIO_MediaLinker_Result;
+
+/* Called once per clip. old_target_url is the ExternalReference's URL, or
+ * NULL when the clip has another kind of reference or none; the strings and
+ * clip_metadata are borrowed for the call. Return KEEP to leave the clip's
+ * reference alone, or UNRESOLVED when no media was found. Return LINKED
+ * after storing a new, unparented reference in *new_reference: the relink
+ * pass takes ownership of it and releases the clip's old reference. A
+ * LINKED result whose *new_reference is the clip's current reference counts
+ * as KEEP, and NULL as UNRESOLVED. */
+typedef OTIO_MediaLinker_Result (*MediaLinkerFunction)(
+        const char *clip_name,
+        AnyDictionary *clip_metadata,
+        const char *old_target_url,
+        MediaReference **new_reference,
+        void *user_data);
+typedef struct MediaLinker MediaLinker;
+
+/* unchanged counts KEEP results and missing counts UNRESOLVED ones. */
+typedef struct {
+    int relinked;
+    int missing;
+    int unchanged;
+} RelinkReport;
+
+/* user_data is passed to link unchanged and must outlive the linker. */
+OTIO_API MediaLinker *MediaLinker_create(
+        const char *name, MediaLinkerFunction link, void *user_data);
+/* The built-in linkers copy the old ExternalReference, keeping its
+ * available range and metadata, with the new URL; clips without one are
+ * kept. Directory search looks for a file with the old URL's decoded file
+ * name and gives a percent-encoded file:// URL, or UNRESOLVED when there is
+ * none. search_directory is made absolute when the linker is created, and
+ * is listed once per relink pass. */
+OTIO_API MediaLinker *MediaLinker_create_directory_search(
+        const char *search_directory, bool recursive);
+/* Replaces old_prefix at the start of the URL; other URLs are kept. */
+OTIO_API MediaLinker *MediaLinker_create_path_prefix_remap(
+        const char *old_prefix, const char *new_prefix);
+/* The returned name is owned by the linker. */
+OTIO_API const char *MediaLinker_name(MediaLinker *self);
+OTIO_API void MediaLinker_destroy(MediaLinker *self);
+
+/* Calls linker for every clip below root, which may be a timeline, a
+ * composition, a collection or a clip. Only each clip's active media
+ * reference is relinked; the rest of its media_references are left as they
+ * are. With missing_if_unresolved, an UNRESOLVED clip gets a
+ * MissingReference carrying the old reference's name, available range and
+ * metadata. Any other root gives TYPE_MISMATCH. */
+OTIO_API bool relink_media(
+        OTIOSerializableObject *root,
+        MediaLinker *linker,
+        bool missing_if_unresolved,
+        RelinkReport *report,
+        OTIOErrorStatus *error_status);
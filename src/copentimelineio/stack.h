@@ -69,10 +69,35 @@ OTIO_API bool Stack_has_child(Stack *self, Composable *child);
 
 OTIO_API bool Stack_visible(Stack *self);
 OTIO_API bool Stack_overlapping(Stack *self);
+OTIO_API bool Stack_enabled(Stack *self);
+OTIO_API void Stack_set_enabled(Stack *self, bool enabled);
 OTIO_API OptionalTimeRange Stack_source_range(Stack *self);
 OTIO_API void Stack_set_source_range(Stack *self, OptionalTimeRange source_range);
 OTIO_API EffectRetainerVector *Stack_effects(Stack *self);
 OTIO_API MarkerRetainerVector *Stack_markers(Stack *self);
+OTIO_API bool Stack_append_effect(
+        Stack *self, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API bool Stack_insert_effect(
+        Stack *self, int index, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API Effect *
+Stack_remove_effect(Stack *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Stack_move_effect(
+        Stack *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Stack_clear_effects(Stack *self);
+OTIO_API int Stack_retain_effects(
+        Stack *self, EffectPredicate predicate, void *user_data);
+OTIO_API bool Stack_append_marker(
+        Stack *self, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API bool Stack_insert_marker(
+        Stack *self, int index, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API Marker *
+Stack_remove_marker(Stack *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Stack_move_marker(
+        Stack *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Stack_clear_markers(Stack *self);
+OTIO_API int Stack_retain_markers(
+        Stack *self, MarkerPredicate predicate, void *user_data);
+OTIO_API void Stack_sort_markers(Stack *self);
 OTIO_API RationalTime Stack_duration(Stack *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Stack_trimmed_range(Stack *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange Stack_visible_range(Stack *self, OTIOErrorStatus *error_status);
@@ -4,6 +4,7 @@
 #include "stack.h"
 #include "track.h"
 #include "trackVector.h"
+#include <stdbool.h>
 
 #ifdef __cplusplus
 # define OTIO_API extern "C"
@@ -15,3 +16,9 @@ OTIO_API Track *flatten_stack(Stack *in_stack, OTIOErrorStatus *error_status);
 
 OTIO_API Track *flatten_stack_track_vector(
         TrackVector *tracks, OTIOErrorStatus *error_status);
+
+OTIO_API Track *flatten_stack_with_options(
+        Stack *in_stack, bool skip_disabled, OTIOErrorStatus *error_status);
+
+OTIO_API Track *flatten_stack_track_vector_with_options(
+        TrackVector *tracks, bool skip_disabled, OTIOErrorStatus *error_status);
@@ -0,0 +1,153 @@
+#pragma once
+
+#include "copentime/optionalOpenTime.h"
+#include "copentime/rationalTime.h"
+#include "copentime/timeRange.h"
+#include "composition.h"
+#include "errorStatus.h"
+#include "item.h"
+#include "marker.h"
+#include "serializableObject.h"
+#include "stack.h"
+#include "timeline.h"
+#include "track.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+/* A visible item under the search time. parent is the composition that
+ * directly holds the item, which is a nested Stack or Track rather than a
+ * top-level track when compositions are nested. media_time is only set for
+ * clips. */
+typedef struct {
+    Item *item;
+    Composition *parent;
+    RationalTime item_time;
+    OptionalRationalTime media_time;
+} ItemAtTime;
+
+typedef struct ItemAtTimeVector ItemAtTimeVector;
+
+OTIO_API int ItemAtTimeVector_size(ItemAtTimeVector *self);
+OTIO_API bool ItemAtTimeVector_empty(ItemAtTimeVector *self);
+OTIO_API ItemAtTime ItemAtTimeVector_at(ItemAtTimeVector *self, int pos);
+OTIO_API void ItemAtTimeVector_destroy(ItemAtTimeVector *self);
+
+/* global_range is the marked range in timeline time, including the
+ * timeline's global_start_time. owner_path joins the names of the items from
+ * the top-level stack down to the owner with '/', and is owned by the
+ * vector. */
+typedef struct {
+    Marker *marker;
+    Item *owner;
+    TimeRange global_range;
+    const char *owner_path;
+} MarkerInTimeline;
+
+typedef struct MarkerInTimelineVector MarkerInTimelineVector;
+
+OTIO_API int MarkerInTimelineVector_size(MarkerInTimelineVector *self);
+OTIO_API bool MarkerInTimelineVector_empty(MarkerInTimelineVector *self);
+OTIO_API MarkerInTimeline
+MarkerInTimelineVector_at(MarkerInTimelineVector *self, int pos);
+OTIO_API void MarkerInTimelineVector_destroy(MarkerInTimelineVector *self);
+
+typedef enum
+{
+    OTIO_RateConform_Mode_PRESERVE_FRAME_COUNT = 0,
+    OTIO_RateConform_Mode_PRESERVE_REAL_TIME   = 1,
+} OTIO_RateConform_Mode_;
+typedef int OTIO_RateConform_Mode;
+
+/* A value that did not land on a whole frame at the new rate and was
+ * rounded. path joins names from the top-level stack with '/', ending in
+ * the marker name or media reference key for their ranges, and is owned by
+ * the vector. field names the value, such as "source_range.start_time";
+ * ranges are conformed by their start and exclusive end. */
+typedef struct {
+    OTIOSerializableObject *object;
+    const char *path;
+    const char *field;
+    RationalTime original_value;
+    RationalTime conformed_value;
+} SubFrameValue;
+
+typedef struct SubFrameValueVector SubFrameValueVector;
+
+OTIO_API int SubFrameValueVector_size(SubFrameValueVector *self);
+OTIO_API bool SubFrameValueVector_empty(SubFrameValueVector *self);
+OTIO_API SubFrameValue
+SubFrameValueVector_at(SubFrameValueVector *self, int pos);
+OTIO_API void SubFrameValueVector_destroy(SubFrameValueVector *self);
+
+/* A range of one media file that the cut uses. media_range is in the media's
+ * time, at the rate of the first clip using it, and includes the handles;
+ * clip_count counts the clips merged into it. handles_clamped is set when
+ * the available range cut a handle short. target_url is owned by the list. */
+typedef struct {
+    const char *target_url;
+    TimeRange media_range;
+    int clip_count;
+    bool handles_clamped;
+} PullListEntry;
+
+/* Entries are sorted by target_url, then by start. */
+typedef struct PullList PullList;
+
+OTIO_API int PullList_size(PullList *self);
+OTIO_API bool PullList_empty(PullList *self);
+OTIO_API PullListEntry PullList_at(PullList *self, int pos);
+/* The exports give whole frames at each entry's rate, widened out to cover
+ * any partial frame, with an exclusive end. They return NULL and set
+ * INVALID_TIME_RANGE when an entry's range is not finite or its rate is not
+ * positive. The returned string is owned by the caller, who frees it. */
+OTIO_API const char *
+PullList_to_csv_string(PullList *self, OTIOErrorStatus *error_status);
+OTIO_API const char *PullList_to_json_string(
+        PullList *self, OTIOErrorStatus *error_status, int indent);
+OTIO_API void PullList_destroy(PullList *self);
+
+/* search_time is in the coordinates of the timeline's top-level stack, the
+ * same space as Timeline_range_of_child; it does not include the timeline's
+ * global_start_time. Items are returned top-down in stack order. */
+OTIO_API ItemAtTimeVector *timeline_items_at_time(
+        Timeline *in_timeline,
+        RationalTime search_time,
+        bool skip_disabled,
+        OTIOErrorStatus *error_status);
+
+OTIO_API ItemAtTimeVector *stack_items_at_time(
+        Stack *in_stack,
+        RationalTime search_time,
+        bool skip_disabled,
+        OTIOErrorStatus *error_status);
+
+OTIO_API MarkerInTimelineVector *timeline_all_markers(
+        Timeline *in_timeline, OTIOErrorStatus *error_status);
+
+/* Rewrites every source range, available range, marker range, transition
+ * offset and the global_start_time of the timeline at new_rate, in place.
+ * PRESERVE_FRAME_COUNT keeps each value's frame count at its own rate;
+ * PRESERVE_REAL_TIME keeps its time in seconds. */
+OTIO_API SubFrameValueVector *timeline_conform_rate(
+        Timeline *in_timeline,
+        double new_rate,
+        OTIO_RateConform_Mode mode,
+        OpenTime_RoundingPolicy rounding_policy,
+        OTIOErrorStatus *error_status);
+
+/* Collects the media each ExternalReference clip consumes, through its
+ * time effects, widened by the handles and merged per target_url where the
+ * ranges overlap or touch. Clips with other references are left out, and
+ * disabled items with everything under them when skip_disabled is set.
+ * Negative handles give INVALID_TIME_RANGE. */
+OTIO_API PullList *timeline_pull_list(
+        Timeline *in_timeline,
+        RationalTime head_handle,
+        RationalTime tail_handle,
+        bool skip_disabled,
+        OTIOErrorStatus *error_status);
@@ -0,0 +1,137 @@
+#pragma once
+
+#include "clip.h"
+#include "copentime/optionalOpenTime.h"
+#include "copentime/rationalTime.h"
+#include "errorStatus.h"
+#include "serializableObject.h"
+#include "timeline.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+typedef enum
+{
+    OTIO_Difference_Kind_ADDED    = 0,
+    OTIO_Difference_Kind_REMOVED  = 1,
+    OTIO_Difference_Kind_MOVED    = 2,
+    OTIO_Difference_Kind_MODIFIED = 3,
+} OTIO_Difference_Kind_;
+typedef int OTIO_Difference_Kind;
+
+typedef enum
+{
+    OTIO_Difference_Field_NONE                = 0,
+    OTIO_Difference_Field_NAME                = 1,
+    OTIO_Difference_Field_SOURCE_RANGE        = 2,
+    OTIO_Difference_Field_MEDIA_REFERENCE_URL = 4,
+    OTIO_Difference_Field_METADATA            = 8,
+    OTIO_Difference_Field_OTHER               = 16,
+} OTIO_Difference_Field_;
+typedef int OTIO_Difference_Field;
+
+/* changed_fields is a mask of OTIO_Difference_Field values; a MOVED object
+ * may also have changed. Children are matched by schema and name, in order,
+ * and otherwise by index, so a renamed child is MODIFIED; a child that
+ * changed parent is MOVED, even out of a removed or into an added
+ * composition. The other children of an added or removed composition are
+ * not listed. Paths join the names from the top-level stack down with
+ * '/', are NULL on the side where the object is missing and are owned by
+ * the vector, as are the changed metadata keys. */
+typedef struct {
+    OTIO_Difference_Kind kind;
+    int changed_fields;
+    OTIOSerializableObject *old_object;
+    OTIOSerializableObject *new_object;
+    const char *old_path;
+    const char *new_path;
+} Difference;
+
+typedef struct DifferenceVector DifferenceVector;
+
+OTIO_API int DifferenceVector_size(DifferenceVector *self);
+OTIO_API bool DifferenceVector_empty(DifferenceVector *self);
+OTIO_API Difference DifferenceVector_at(DifferenceVector *self, int pos);
+OTIO_API int DifferenceVector_changed_metadata_key_count(
+        DifferenceVector *self, int pos);
+OTIO_API const char *DifferenceVector_changed_metadata_key(
+        DifferenceVector *self, int pos, int key_index);
+OTIO_API void DifferenceVector_destroy(DifferenceVector *self);
+
+/* Both return NULL and set error_status when either side is NULL, or for
+ * diff_timelines when a timeline has no tracks. */
+OTIO_API DifferenceVector *diff_serializable_objects(
+        OTIOSerializableObject *old_object,
+        OTIOSerializableObject *new_object,
+        OTIOErrorStatus *error_status);
+
+OTIO_API DifferenceVector *diff_timelines(
+        Timeline *old_timeline,
+        Timeline *new_timeline,
+        OTIOErrorStatus *error_status);
+
+typedef enum
+{
+    OTIO_CutChange_MatchBy_NAME         = 0,
+    OTIO_CutChange_MatchBy_MEDIA_URL    = 1,
+    OTIO_CutChange_MatchBy_METADATA_KEY = 2,
+} OTIO_CutChange_MatchBy_;
+typedef int OTIO_CutChange_MatchBy;
+
+typedef enum
+{
+    OTIO_CutChange_Kind_INSERT = 0,
+    OTIO_CutChange_Kind_OMIT   = 1,
+    OTIO_CutChange_Kind_TRIM   = 2,
+    OTIO_CutChange_Kind_SLIP   = 3,
+    OTIO_CutChange_Kind_MOVE   = 4,
+} OTIO_CutChange_Kind_;
+typedef int OTIO_CutChange_Kind;
+
+/* The ranges in timeline are record ranges, including the timeline's
+ * global_start_time. head_delta and tail_delta are whole frames at the old
+ * clip's rate and are positive when the new cut uses more media on that
+ * side; a SLIP moves both by the same amount. A clip that moved and was
+ * trimmed appears once for each change. */
+typedef struct {
+    OTIO_CutChange_Kind kind;
+    Clip *old_clip;
+    Clip *new_clip;
+    OptionalTimeRange old_range_in_timeline;
+    OptionalTimeRange new_range_in_timeline;
+    RationalTime head_delta;
+    RationalTime tail_delta;
+} CutChange;
+
+typedef struct CutChangeList CutChangeList;
+
+OTIO_API int CutChangeList_size(CutChangeList *self);
+OTIO_API bool CutChangeList_empty(CutChangeList *self);
+OTIO_API CutChange CutChangeList_at(CutChangeList *self, int pos);
+/* Record ranges are written as timecode at rate with an exclusive out
+ * point, and deltas as frames at rate. The returned string is allocated
+ * with malloc and owned by the caller. */
+OTIO_API const char *CutChangeList_to_csv_string(
+        CutChangeList *self, double rate, OTIOErrorStatus *error_status);
+OTIO_API const char *CutChangeList_to_json_string(
+        CutChangeList *self,
+        double rate,
+        OTIOErrorStatus *error_status,
+        int indent);
+OTIO_API void CutChangeList_destroy(CutChangeList *self);
+
+/* Matches the clips of the top-level tracks of track_kind (NULL for
+ * "Video") in both cuts by match_by, pairing clips with the same key in cut
+ * order. metadata_key names a string metadata entry and is only used with
+ * OTIO_CutChange_MatchBy_METADATA_KEY. Changes are listed in record order. */
+OTIO_API CutChangeList *compare_cuts(
+        Timeline *old_timeline,
+        Timeline *new_timeline,
+        const char *track_kind,
+        OTIO_CutChange_MatchBy match_by,
+        const char *metadata_key,
+        OTIOErrorStatus *error_status);
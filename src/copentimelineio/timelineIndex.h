@@ -0,0 +1,48 @@
+#pragma once
+
+#include "copentime/rationalTime.h"
+#include "copentime/timeRange.h"
+#include "errorStatus.h"
+#include "item.h"
+#include "timeline.h"
+#include "track.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+/* An item of one of the timeline's top-level tracks. range is in the
+ * coordinates of the timeline's top-level stack. */
+typedef struct {
+    Item *item;
+    Track *track;
+    TimeRange range;
+} IndexedItem;
+
+typedef struct IndexedItemVector IndexedItemVector;
+
+OTIO_API int IndexedItemVector_size(IndexedItemVector *self);
+OTIO_API bool IndexedItemVector_empty(IndexedItemVector *self);
+OTIO_API IndexedItem IndexedItemVector_at(IndexedItemVector *self, int pos);
+OTIO_API void IndexedItemVector_destroy(IndexedItemVector *self);
+
+/* A snapshot of the item ranges of a timeline. The index does not observe
+ * the timeline: after any edit it must be refreshed with
+ * TimelineIndex_rebuild, which is the only way to invalidate it. The index
+ * retains the timeline until it is destroyed. Queries return items ordered
+ * by start time, then top-down in stack order. */
+typedef struct TimelineIndex TimelineIndex;
+
+OTIO_API TimelineIndex *
+TimelineIndex_create(Timeline *timeline, OTIOErrorStatus *error_status);
+OTIO_API void TimelineIndex_destroy(TimelineIndex *self);
+OTIO_API bool
+TimelineIndex_rebuild(TimelineIndex *self, OTIOErrorStatus *error_status);
+OTIO_API int TimelineIndex_size(TimelineIndex *self);
+OTIO_API IndexedItemVector *
+TimelineIndex_items_at_time(TimelineIndex *self, RationalTime time);
+OTIO_API IndexedItemVector *TimelineIndex_items_overlapping_range(
+        TimelineIndex *self, TimeRange time_range);
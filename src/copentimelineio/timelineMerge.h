@@ -0,0 +1,69 @@
+#pragma once
+
+#include "errorStatus.h"
+#include "serializableObject.h"
+#include "stack.h"
+#include "timeline.h"
+#include "track.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+typedef enum
+{
+    OTIO_MergeConflict_Kind_BOTH_MODIFIED        = 0,
+    OTIO_MergeConflict_Kind_MODIFIED_AND_REMOVED = 1,
+    OTIO_MergeConflict_Kind_BOTH_ADDED           = 2,
+    OTIO_MergeConflict_Kind_OVERLAPPING_EDIT     = 3,
+} OTIO_MergeConflict_Kind_;
+typedef int OTIO_MergeConflict_Kind;
+
+/* The objects are the conflicting versions from the inputs, NULL where a
+ * version does not exist; path joins names from the merged composition down
+ * with '/' and is owned by the result. OVERLAPPING_EDIT marks both sides
+ * inserting at the same place in a track, or reordering children
+ * differently. */
+typedef struct {
+    OTIO_MergeConflict_Kind kind;
+    OTIOSerializableObject *base_object;
+    OTIOSerializableObject *ours_object;
+    OTIOSerializableObject *theirs_object;
+    const char *path;
+} MergeConflict;
+
+typedef struct MergeConflictVector MergeConflictVector;
+typedef struct MergeResult MergeResult;
+
+OTIO_API int MergeConflictVector_size(MergeConflictVector *self);
+OTIO_API bool MergeConflictVector_empty(MergeConflictVector *self);
+OTIO_API MergeConflict
+MergeConflictVector_at(MergeConflictVector *self, int pos);
+OTIO_API void MergeConflictVector_destroy(MergeConflictVector *self);
+
+/* The merged object is a new object owned by the result; retain it to keep
+ * it past MergeResult_destroy. Where the sides conflict it holds ours, or
+ * the modified version of a child the other side removed. The vector from
+ * MergeResult_conflicts is the caller's to destroy, but refers to the
+ * result and must not outlive it. */
+OTIO_API OTIOSerializableObject *MergeResult_merged_object(MergeResult *self);
+OTIO_API bool MergeResult_has_conflicts(MergeResult *self);
+OTIO_API MergeConflictVector *MergeResult_conflicts(MergeResult *self);
+OTIO_API void MergeResult_destroy(MergeResult *self);
+
+/* Children are matched by schema and name, in order. Returns NULL only if
+ * an object cannot be cloned or a merged child cannot be added. */
+OTIO_API MergeResult *merge_timelines(
+        Timeline *base,
+        Timeline *ours,
+        Timeline *theirs,
+        OTIOErrorStatus *error_status);
+
+OTIO_API MergeResult *merge_stacks(
+        Stack *base, Stack *ours, Stack *theirs, OTIOErrorStatus *error_status);
+
+OTIO_API MergeResult *merge_tracks(
+        Track *base, Track *ours, Track *theirs, OTIOErrorStatus *error_status);
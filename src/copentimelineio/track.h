@@ -52,6 +52,8 @@ OTIO_API RetainerPairComposable *Track_neighbors_of(
         OTIOErrorStatus *error_status,
         OTIO_Track_NeighbourGapPolicy insert_gap);
 OTIO_API ComposableVector *Track_each_clip(Track *self);
+OTIO_API ComposableVector *
+Track_each_clip_with_options(Track *self, bool skip_disabled);
 OTIO_API MapComposableTimeRange *
 Track_range_of_all_children(Track *self, OTIOErrorStatus *error_status);
 
@@ -83,10 +85,35 @@ OTIO_API OptionalTimeRange Track_trim_child_range(Track *self, TimeRange child_r
 OTIO_API bool Track_has_child(Track *self, Composable *child);
 OTIO_API bool Track_visible(Track *self);
 OTIO_API bool Track_overlapping(Track *self);
+OTIO_API bool Track_enabled(Track *self);
+OTIO_API void Track_set_enabled(Track *self, bool enabled);
 OTIO_API OptionalTimeRange Track_source_range(Track *self);
 OTIO_API void Track_set_source_range(Track *self, OptionalTimeRange source_range);
 OTIO_API EffectRetainerVector *Track_effects(Track *self);
 OTIO_API MarkerRetainerVector *Track_markers(Track *self);
+OTIO_API bool Track_append_effect(
+        Track *self, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API bool Track_insert_effect(
+        Track *self, int index, Effect *effect, OTIOErrorStatus *error_status);
+OTIO_API Effect *
+Track_remove_effect(Track *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Track_move_effect(
+        Track *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Track_clear_effects(Track *self);
+OTIO_API int Track_retain_effects(
+        Track *self, EffectPredicate predicate, void *user_data);
+OTIO_API bool Track_append_marker(
+        Track *self, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API bool Track_insert_marker(
+        Track *self, int index, Marker *marker, OTIOErrorStatus *error_status);
+OTIO_API Marker *
+Track_remove_marker(Track *self, int index, OTIOErrorStatus *error_status);
+OTIO_API bool Track_move_marker(
+        Track *self, int from_index, int to_index, OTIOErrorStatus *error_status);
+OTIO_API void Track_clear_markers(Track *self);
+OTIO_API int Track_retain_markers(
+        Track *self, MarkerPredicate predicate, void *user_data);
+OTIO_API void Track_sort_markers(Track *self);
 OTIO_API RationalTime Track_duration(Track *self, OTIOErrorStatus *error_status);
 OTIO_API TimeRange
 Track_available_range(Track *self, OTIOErrorStatus *error_status);
@@ -3,6 +3,7 @@
 #include "copentime/timeRange.h"
 #include "errorStatus.h"
 #include "track.h"
+#include <stdbool.h>
 
 #ifdef __cplusplus
 # define OTIO_API extern "C"
@@ -11,4 +12,27 @@
 #endif
 
 OTIO_API Track *track_trimmed_to_range(
-        Track *in_track, TimeRange trim_range, OTIOErrorStatus *error_status);
\ No newline at end of file
+        Track *in_track, TimeRange trim_range, OTIOErrorStatus *error_status);
+
+/* Counts of what track_normalize_gaps changed. A gap carrying effects or
+ * markers is never merged or removed; annotated_gaps_kept counts those that
+ * would otherwise have been. */
+typedef struct {
+    int merged_gaps;
+    int removed_zero_length_gaps;
+    int removed_leading_gaps;
+    int removed_trailing_gaps;
+    int annotated_gaps_kept;
+} GapNormalizationReport;
+
+/* Merges runs of adjacent gaps into the first gap of the run, removes
+ * zero-length gaps and, if asked, leading and trailing gaps. Gaps that a
+ * transition depends on as a neighbour are kept. report may be NULL. On
+ * failure the track is left unchanged, unless restoring it also failed,
+ * which error_status then reports. */
+OTIO_API bool track_normalize_gaps(
+        Track *in_track,
+        bool strip_leading,
+        bool strip_trailing,
+        GapNormalizationReport *report,
+        OTIOErrorStatus *error_status);
@@ -0,0 +1,64 @@
+#pragma once
+
+#include "errorStatus.h"
+#include "serializableObject.h"
+#include "timeline.h"
+#include <stdbool.h>
+
+#ifdef __cplusplus
+# define OTIO_API extern "C"
+#else
+# define OTIO_API
+#endif
+
+typedef enum
+{
+    OTIO_Diagnostic_Severity_INFO    = 0,
+    OTIO_Diagnostic_Severity_WARNING = 1,
+    OTIO_Diagnostic_Severity_ERROR   = 2,
+} OTIO_Diagnostic_Severity_;
+typedef int OTIO_Diagnostic_Severity;
+
+typedef enum
+{
+    OTIO_Diagnostic_Kind_TRANSITION_AT_TRACK_EDGE             = 0,
+    OTIO_Diagnostic_Kind_ADJACENT_TRANSITIONS                 = 1,
+    OTIO_Diagnostic_Kind_TRANSITION_EXCEEDS_HANDLES           = 2,
+    OTIO_Diagnostic_Kind_NEGATIVE_DURATION                    = 3,
+    OTIO_Diagnostic_Kind_ZERO_DURATION                        = 4,
+    OTIO_Diagnostic_Kind_SOURCE_RANGE_OUTSIDE_AVAILABLE_RANGE = 5,
+    OTIO_Diagnostic_Kind_MIXED_FRAME_RATES                    = 6,
+    OTIO_Diagnostic_Kind_MISSING_MEDIA_REFERENCE              = 7,
+    OTIO_Diagnostic_Kind_UNKNOWN_SCHEMA                       = 8,
+} OTIO_Diagnostic_Kind_;
+typedef int OTIO_Diagnostic_Kind;
+
+/* object is the offending object and object_path joins the names from the
+ * top-level stack down to it with '/'; for values found in metadata the
+ * path continues with the metadata keys. Both strings are owned by the
+ * vector. */
+typedef struct {
+    OTIO_Diagnostic_Severity severity;
+    OTIO_Diagnostic_Kind kind;
+    OTIOSerializableObject *object;
+    const char *object_path;
+    const char *message;
+} Diagnostic;
+
+typedef struct DiagnosticVector DiagnosticVector;
+
+OTIO_API int DiagnosticVector_size(DiagnosticVector *self);
+OTIO_API bool DiagnosticVector_empty(DiagnosticVector *self);
+OTIO_API Diagnostic DiagnosticVector_at(DiagnosticVector *self, int pos);
+OTIO_API void DiagnosticVector_destroy(DiagnosticVector *self);
+
+OTIO_API const char *Diagnostic_kind_to_string(OTIO_Diagnostic_Kind kind);
+
+/* Reports transitions at track edges, next to other transitions or needing
+ * more media than their neighbours have, non-positive durations, source
+ * ranges outside the media, rates that differ from the first item's,
+ * missing media references and unknown schemas in metadata. Returns NULL
+ * only when a range cannot be computed; problems in the timeline itself are
+ * reported as diagnostics. */
+OTIO_API DiagnosticVector *
+validate_timeline(Timeline *in_timeline, OTIOErrorStatus *error_status);
@@ -0,0 +1,69 @@
+//! Errors reported through `OTIOErrorStatus`.
+
+use crate::bindings::*;
+use std::ffi::{CStr, NulError};
+use std::fmt;
+
+/// A failed call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// OpenTimelineIO reported a failure, identified by its outcome.
+    Outcome(OTIO_ErrorStatus_Outcome),
+    /// A string argument contained a nul byte at `position`, so it could not
+    /// be passed to C.
+    InteriorNul { position: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::Outcome(outcome) => {
+                let message = unsafe { CStr::from_ptr(OTIOErrorStatus_outcome_to_string(outcome)) };
+                f.write_str(&message.to_string_lossy())
+            }
+            Error::InteriorNul { position } => {
+                write!(f, "string contains a nul byte at position {}", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<NulError> for Error {
+    fn from(error: NulError) -> Self {
+        Error::InteriorNul {
+            position: error.nul_position(),
+        }
+    }
+}
+
+/// An owned `OTIOErrorStatus` to pass to calls that report failures.
+pub(crate) struct ErrorStatus(*mut OTIOErrorStatus);
+
+impl ErrorStatus {
+    pub(crate) fn new() -> Self {
+        ErrorStatus(unsafe { OTIOErrorStatus_create() })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut OTIOErrorStatus {
+        self.0
+    }
+
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        let outcome = unsafe { OTIOErrorStatus_get_outcome(self.0) };
+        if outcome
+            == OTIO_ErrorStatus_Outcome__OTIO_ErrorStatus_Outcome_OK as OTIO_ErrorStatus_Outcome
+        {
+            Ok(())
+        } else {
+            Err(Error::Outcome(outcome))
+        }
+    }
+}
+
+impl Drop for ErrorStatus {
+    fn drop(&mut self) {
+        unsafe { OTIOErrorStatus_destroy(self.0) }
+    }
+}
@@ -0,0 +1,215 @@
+//! Typed track kinds, marker colors and transition types.
+//!
+//! The C API stores these as free-form strings. The enums below name the
+//! standard values and keep anything else verbatim in `Custom`, so parsing
+//! and displaying round-trips every string. A `Custom` value can only be made
+//! by parsing, so it never spells one of the standard values.
+
+use crate::bindings::*;
+use crate::error::Error;
+use std::convert::Infallible;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::str::FromStr;
+
+extern "C" {
+    fn free(ptr: *mut c_void);
+}
+
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])* $name:ident / $custom:ident {
+            $($variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            /// Any other value, kept as written.
+            Custom($custom),
+        }
+
+        #[doc = concat!("A non-standard [`", stringify!($name), "`], made by parsing.")]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $custom(String);
+
+        impl $custom {
+            /// The value as written.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl $name {
+            /// The string stored in the schema for this value.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Custom(value) => value.as_str(),
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Custom($custom(value.to_owned())),
+                })
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// The kind of a track; `Timeline_video_tracks` and
+    /// `Timeline_audio_tracks` only recognise the standard kinds.
+    TrackKind / CustomTrackKind {
+        Video => "Video",
+        Audio => "Audio",
+    }
+}
+
+string_enum! {
+    /// The color of a marker.
+    MarkerColor / CustomMarkerColor {
+        Pink => "PINK",
+        Red => "RED",
+        Orange => "ORANGE",
+        Yellow => "YELLOW",
+        Green => "GREEN",
+        Cyan => "CYAN",
+        Blue => "BLUE",
+        Purple => "PURPLE",
+        Magenta => "MAGENTA",
+        Black => "BLACK",
+        White => "WHITE",
+    }
+}
+
+string_enum! {
+    /// The type of a transition.
+    TransitionType / CustomTransitionType {
+        SmpteDissolve => "SMPTE_Dissolve",
+    }
+}
+
+// Parses and frees a string returned by a C getter, which the caller owns.
+unsafe fn take_string<T: FromStr<Err = Infallible>>(value: *const c_char) -> T {
+    let parsed = CStr::from_ptr(value).to_string_lossy().parse();
+    free(value as *mut c_void);
+    parsed.unwrap_or_else(|never| match never {})
+}
+
+fn c_string(value: &str) -> Result<CString, Error> {
+    Ok(CString::new(value)?)
+}
+
+/// Returns the kind of `track`.
+///
+/// # Safety
+///
+/// `track` must be a valid track.
+pub unsafe fn track_kind(track: *mut Track) -> TrackKind {
+    take_string(Track_kind(track))
+}
+
+/// Sets the kind of `track`, failing if a custom kind contains a nul byte.
+///
+/// # Safety
+///
+/// `track` must be a valid track.
+pub unsafe fn set_track_kind(track: *mut Track, kind: &TrackKind) -> Result<(), Error> {
+    Track_set_kind(track, c_string(kind.as_str())?.as_ptr());
+    Ok(())
+}
+
+/// Returns the color of `marker`.
+///
+/// # Safety
+///
+/// `marker` must be a valid marker.
+pub unsafe fn marker_color(marker: *mut Marker) -> MarkerColor {
+    take_string(Marker_color(marker))
+}
+
+/// Sets the color of `marker`, failing if a custom color contains a nul
+/// byte.
+///
+/// # Safety
+///
+/// `marker` must be a valid marker.
+pub unsafe fn set_marker_color(marker: *mut Marker, color: &MarkerColor) -> Result<(), Error> {
+    Marker_set_color(marker, c_string(color.as_str())?.as_ptr());
+    Ok(())
+}
+
+/// Returns the type of `transition`.
+///
+/// # Safety
+///
+/// `transition` must be a valid transition.
+pub unsafe fn transition_type(transition: *mut Transition) -> TransitionType {
+    take_string(Transition_transition_type(transition))
+}
+
+/// Sets the type of `transition`, failing if a custom type contains a nul
+/// byte.
+///
+/// # Safety
+///
+/// `transition` must be a valid transition.
+pub unsafe fn set_transition_type(
+    transition: *mut Transition,
+    transition_type: &TransitionType,
+) -> Result<(), Error> {
+    Transition_set_transition_type(transition, c_string(transition_type.as_str())?.as_ptr());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_values_parse() {
+        assert_eq!("Video".parse(), Ok(TrackKind::Video));
+        assert_eq!("RED".parse(), Ok(MarkerColor::Red));
+        assert_eq!("SMPTE_Dissolve".parse(), Ok(TransitionType::SmpteDissolve));
+    }
+
+    #[test]
+    fn other_values_round_trip() {
+        for value in ["video", "Subtitles", ""] {
+            let kind: TrackKind = value.parse().unwrap();
+            assert!(matches!(&kind, TrackKind::Custom(custom) if custom.as_str() == value));
+            assert_eq!(kind.to_string(), value);
+        }
+        let custom: TransitionType = "Custom_Transition".parse().unwrap();
+        assert_eq!(custom.to_string(), "Custom_Transition");
+    }
+
+    #[test]
+    fn nul_bytes_are_rejected() {
+        assert_eq!(
+            c_string("Sub\0titles"),
+            Err(Error::InteriorNul { position: 3 })
+        );
+    }
+
+    #[test]
+    fn display_matches_schema_strings() {
+        assert_eq!(TrackKind::Audio.to_string(), "Audio");
+        assert_eq!(MarkerColor::Magenta.to_string(), "MAGENTA");
+    }
+}
@@ -1,14 +1,18 @@
 #include "copentime/errorStatus.h"
+#include "copentime/exactRationalTime.h"
 #include "copentime/optionalOpenTime.h"
 #include "copentime/rationalTime.h"
 #include "copentime/timeRange.h"
+#include "copentime/timeRangeSet.h"
 #include "copentime/timeTransform.h"
+#include "copentime/timecode.h"
 #include "copentimelineio/any.h"
 #include "copentimelineio/anyDictionary.h"
 #include "copentimelineio/anyVector.h"
 #include "copentimelineio/clip.h"
 #include "copentimelineio/composable.h"
 #include "copentimelineio/composableRetainerVector.h"
+#include "copentimelineio/composableSearch.h"
 #include "copentimelineio/composableVector.h"
 #include "copentimelineio/composition.h"
 #include "copentimelineio/deserialization.h"
@@ -20,12 +24,14 @@
 #include "copentimelineio/freezeFrame.h"
 #include "copentimelineio/gap.h"
 #include "copentimelineio/generatorReference.h"
+#include "copentimelineio/imageSequenceReference.h"
 #include "copentimelineio/item.h"
 #include "copentimelineio/linearTimeWarp.h"
 #include "copentimelineio/mapComposableTimeRange.h"
 #include "copentimelineio/marker.h"
 #include "copentimelineio/markerRetainerVector.h"
 #include "copentimelineio/markerVector.h"
+#include "copentimelineio/mediaLinker.h"
 #include "copentimelineio/mediaReference.h"
 #include "copentimelineio/missingReference.h"
 #include "copentimelineio/optionalPairRationalTime.h"
@@ -41,6 +47,10 @@
 #include "copentimelineio/stackAlgorithm.h"
 #include "copentimelineio/timeEffect.h"
 #include "copentimelineio/timeline.h"
+#include "copentimelineio/timelineAlgorithm.h"
+#include "copentimelineio/timelineDiff.h"
+#include "copentimelineio/timelineIndex.h"
+#include "copentimelineio/timelineMerge.h"
 #include "copentimelineio/track.h"
 #include "copentimelineio/trackAlgorithm.h"
 #include "copentimelineio/trackVector.h"
@@ -48,3 +58,4 @@
 #include "copentimelineio/typeInfo.h"
 #include "copentimelineio/typeRegistry.h"
 #include "copentimelineio/unknownSchema.h"
+#include "copentimelineio/validation.h"
@@ -0,0 +1,68 @@
+//! Conversions between the C optional structs and `Option`.
+
+use crate::bindings::*;
+
+impl From<Option<RationalTime>> for OptionalRationalTime {
+    fn from(time: Option<RationalTime>) -> Self {
+        OptionalRationalTime {
+            value: time.unwrap_or(RationalTime {
+                value: 0.0,
+                rate: 1.0,
+            }),
+            valid: time.is_some(),
+        }
+    }
+}
+
+impl From<OptionalRationalTime> for Option<RationalTime> {
+    fn from(time: OptionalRationalTime) -> Self {
+        time.valid.then_some(time.value)
+    }
+}
+
+impl From<Option<TimeRange>> for OptionalTimeRange {
+    fn from(range: Option<TimeRange>) -> Self {
+        let zero = RationalTime {
+            value: 0.0,
+            rate: 1.0,
+        };
+        OptionalTimeRange {
+            value: range.unwrap_or(TimeRange {
+                start_time: zero,
+                duration: zero,
+            }),
+            valid: range.is_some(),
+        }
+    }
+}
+
+impl From<OptionalTimeRange> for Option<TimeRange> {
+    fn from(range: OptionalTimeRange) -> Self {
+        range.valid.then_some(range.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optional_time_range_round_trips() {
+        let range = TimeRange {
+            start_time: RationalTime {
+                value: 10.0,
+                rate: 24.0,
+            },
+            duration: RationalTime {
+                value: 5.0,
+                rate: 24.0,
+            },
+        };
+        let optional = OptionalTimeRange::from(Some(range));
+        assert!(optional.valid);
+        let back: Option<TimeRange> = optional.into();
+        assert_eq!(back.unwrap().start_time.value, 10.0);
+        let none: Option<TimeRange> = OptionalTimeRange::from(None).into();
+        assert!(none.is_none());
+    }
+}
@@ -0,0 +1,168 @@
+//! Media relinking, built on `relink_media`.
+
+use crate::bindings;
+use crate::bindings::*;
+use crate::error::{Error, ErrorStatus};
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+
+/// What a linker decided for one clip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Link {
+    /// Leave the clip's media reference as it is.
+    Keep,
+    /// Replace the clip's media reference. `relink_media` takes ownership of
+    /// an unparented reference and releases the old one; the clip's current
+    /// reference counts as `Keep` and a null pointer as `Unresolved`.
+    Linked(*mut MediaReference),
+    /// The media could not be found.
+    Unresolved,
+}
+
+/// Finds new media for the clips of a timeline.
+pub trait MediaLinker {
+    /// The name reported by [`Linker::name`].
+    fn name(&self) -> &str;
+
+    /// Decides the media for one clip. `old_target_url` is `None` when the
+    /// clip's reference is not an external reference; `clip_metadata` is
+    /// borrowed for the call.
+    fn link(
+        &mut self,
+        clip_name: &str,
+        clip_metadata: *mut AnyDictionary,
+        old_target_url: Option<&str>,
+    ) -> Link;
+}
+
+impl<T: MediaLinker + ?Sized> MediaLinker for &mut T {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn link(
+        &mut self,
+        clip_name: &str,
+        clip_metadata: *mut AnyDictionary,
+        old_target_url: Option<&str>,
+    ) -> Link {
+        (**self).link(clip_name, clip_metadata, old_target_url)
+    }
+}
+
+/// An object whose clips can be relinked.
+pub trait RelinkRoot {}
+
+impl RelinkRoot for Timeline {}
+impl RelinkRoot for Stack {}
+impl RelinkRoot for Track {}
+impl RelinkRoot for Composition {}
+impl RelinkRoot for SerializableCollection {}
+impl RelinkRoot for Clip {}
+
+type BoxedLinker<'a> = Box<dyn MediaLinker + 'a>;
+
+/// An owned `MediaLinker`, either built in or wrapping a [`MediaLinker`].
+pub struct Linker<'a> {
+    linker: *mut bindings::MediaLinker,
+    // Kept alive for the C side, which holds a pointer to it.
+    _link: Option<Box<BoxedLinker<'a>>>,
+}
+
+impl<'a> Linker<'a> {
+    /// Wraps `link` so that it can be passed to [`Linker::relink`]. Fails
+    /// when its name contains a nul byte.
+    pub fn new(link: impl MediaLinker + 'a) -> Result<Self, Error> {
+        let mut link: Box<BoxedLinker<'a>> = Box::new(Box::new(link));
+        let name = CString::new(link.name())?;
+        let user_data = &mut *link as *mut BoxedLinker<'a> as *mut c_void;
+        let linker = unsafe { MediaLinker_create(name.as_ptr(), Some(call_link), user_data) };
+        Ok(Linker {
+            linker,
+            _link: Some(link),
+        })
+    }
+
+    /// Looks for each external reference's file name in `search_directory`.
+    pub fn directory_search(search_directory: &str, recursive: bool) -> Result<Self, Error> {
+        let search_directory = CString::new(search_directory)?;
+        Ok(Linker {
+            linker: unsafe {
+                MediaLinker_create_directory_search(search_directory.as_ptr(), recursive)
+            },
+            _link: None,
+        })
+    }
+
+    /// Replaces `old_prefix` with `new_prefix` in external reference URLs.
+    pub fn path_prefix_remap(old_prefix: &str, new_prefix: &str) -> Result<Self, Error> {
+        let old_prefix = CString::new(old_prefix)?;
+        let new_prefix = CString::new(new_prefix)?;
+        Ok(Linker {
+            linker: unsafe {
+                MediaLinker_create_path_prefix_remap(old_prefix.as_ptr(), new_prefix.as_ptr())
+            },
+            _link: None,
+        })
+    }
+
+    pub fn name(&self) -> Cow<'_, str> {
+        unsafe { CStr::from_ptr(MediaLinker_name(self.linker)) }.to_string_lossy()
+    }
+
+    /// Relinks every clip below `root`, replacing unresolved media with a
+    /// `MissingReference` when `missing_if_unresolved` is set.
+    ///
+    /// # Safety
+    ///
+    /// `root` must be a valid object.
+    pub unsafe fn relink<R: RelinkRoot>(
+        &mut self,
+        root: *mut R,
+        missing_if_unresolved: bool,
+    ) -> Result<RelinkReport, Error> {
+        let error_status = ErrorStatus::new();
+        let mut report = RelinkReport {
+            relinked: 0,
+            missing: 0,
+            unchanged: 0,
+        };
+        relink_media(
+            root as *mut OTIOSerializableObject,
+            self.linker,
+            missing_if_unresolved,
+            &mut report,
+            error_status.as_ptr(),
+        );
+        error_status.check().map(|()| report)
+    }
+}
+
+unsafe extern "C" fn call_link(
+    clip_name: *const c_char,
+    clip_metadata: *mut AnyDictionary,
+    old_target_url: *const c_char,
+    new_reference: *mut *mut MediaReference,
+    user_data: *mut c_void,
+) -> OTIO_MediaLinker_Result {
+    let link = &mut *(user_data as *mut BoxedLinker<'_>);
+    let clip_name = CStr::from_ptr(clip_name).to_string_lossy();
+    let old_target_url =
+        (!old_target_url.is_null()).then(|| CStr::from_ptr(old_target_url).to_string_lossy());
+    let result = match link.link(&clip_name, clip_metadata, old_target_url.as_deref()) {
+        Link::Keep => OTIO_MediaLinker_Result__OTIO_MediaLinker_Result_KEEP,
+        Link::Linked(reference) => {
+            *new_reference = reference;
+            OTIO_MediaLinker_Result__OTIO_MediaLinker_Result_LINKED
+        }
+        Link::Unresolved => OTIO_MediaLinker_Result__OTIO_MediaLinker_Result_UNRESOLVED,
+    };
+    result as OTIO_MediaLinker_Result
+}
+
+impl<'a> Drop for Linker<'a> {
+    fn drop(&mut self) {
+        unsafe { MediaLinker_destroy(self.linker) }
+    }
+}
@@ -0,0 +1,216 @@
+//! Typed, lazy searches over a timeline tree, built on `ComposableSearch`.
+
+use crate::bindings::*;
+use crate::error::{Error, ErrorStatus};
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// A composable type that a search can be narrowed to.
+///
+/// # Safety
+///
+/// `SCHEMA_NAME` must be the schema of `Self`, or a base schema that only
+/// matches objects which can be used as `Self`.
+pub unsafe trait Schema {
+    const SCHEMA_NAME: &'static str;
+}
+
+unsafe impl Schema for Composable {
+    const SCHEMA_NAME: &'static str = "Composable";
+}
+
+unsafe impl Schema for Item {
+    const SCHEMA_NAME: &'static str = "Item";
+}
+
+unsafe impl Schema for Composition {
+    const SCHEMA_NAME: &'static str = "Composition";
+}
+
+unsafe impl Schema for Clip {
+    const SCHEMA_NAME: &'static str = "Clip";
+}
+
+unsafe impl Schema for Gap {
+    const SCHEMA_NAME: &'static str = "Gap";
+}
+
+unsafe impl Schema for Stack {
+    const SCHEMA_NAME: &'static str = "Stack";
+}
+
+unsafe impl Schema for Track {
+    const SCHEMA_NAME: &'static str = "Track";
+}
+
+unsafe impl Schema for Transition {
+    const SCHEMA_NAME: &'static str = "Transition";
+}
+
+/// An object whose children can be searched.
+pub trait SearchRoot {}
+
+impl SearchRoot for Timeline {}
+impl SearchRoot for Stack {}
+impl SearchRoot for Track {}
+impl SearchRoot for Composition {}
+impl SearchRoot for SerializableCollection {}
+
+struct Predicate<'a> {
+    predicate: Box<dyn FnMut(*mut Composable) -> bool + 'a>,
+    // Set when the predicate panicked; the C side saw a non-match.
+    panicked: bool,
+}
+
+/// Iterator over the matches of a search, in pre-order.
+///
+/// Yields children borrowed from their parents, which stay valid while the
+/// root is borrowed. A panicking predicate is reported as an
+/// `INTERNAL_ERROR`. After an error is yielded the iterator is exhausted.
+pub struct FindChildren<'a, T> {
+    search: *mut ComposableSearch,
+    error_status: ErrorStatus,
+    failed: bool,
+    // Kept alive for the C side, which holds a pointer to it.
+    predicate: Option<Box<Predicate<'a>>>,
+    _schema: PhantomData<*mut T>,
+}
+
+impl<'a, T> FindChildren<'a, T> {
+    fn new<R: SearchRoot>(
+        root: &'a R,
+        schema_name: Option<&str>,
+        search_range: Option<TimeRange>,
+        shallow_search: bool,
+        predicate: Option<Box<dyn FnMut(*mut Composable) -> bool + 'a>>,
+    ) -> Self {
+        let schema_name = schema_name.map(|name| CString::new(name).unwrap());
+        let mut predicate = predicate.map(|predicate| {
+            Box::new(Predicate {
+                predicate,
+                panicked: false,
+            })
+        });
+        let (callback, user_data): (ComposablePredicate, *mut c_void) = match &mut predicate {
+            Some(predicate) => (
+                Some(call_predicate),
+                &mut **predicate as *mut Predicate<'a> as *mut c_void,
+            ),
+            None => (None, ptr::null_mut()),
+        };
+        let error_status = ErrorStatus::new();
+        let search = unsafe {
+            ComposableSearch_create(
+                root as *const R as *mut OTIOSerializableObject,
+                schema_name
+                    .as_ref()
+                    .map_or(ptr::null(), |name| name.as_ptr()),
+                search_range.into(),
+                shallow_search,
+                callback,
+                user_data,
+                error_status.as_ptr(),
+            )
+        };
+        // A failed create leaves its outcome in error_status for the first
+        // call to next.
+        FindChildren {
+            search,
+            error_status,
+            failed: false,
+            predicate,
+            _schema: PhantomData,
+        }
+    }
+}
+
+unsafe extern "C" fn call_predicate(child: *mut Composable, user_data: *mut c_void) -> bool {
+    let predicate = &mut *(user_data as *mut Predicate<'_>);
+    let call = &mut predicate.predicate;
+    match panic::catch_unwind(AssertUnwindSafe(|| call(child))) {
+        Ok(matched) => matched,
+        Err(_) => {
+            predicate.panicked = true;
+            false
+        }
+    }
+}
+
+impl<'a, T> Iterator for FindChildren<'a, T> {
+    type Item = Result<*mut T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        if self.search.is_null() {
+            self.failed = true;
+            return self.error_status.check().err().map(Err);
+        }
+        let child = unsafe { ComposableSearch_next(self.search, self.error_status.as_ptr()) };
+        if let Err(error) = self.error_status.check() {
+            self.failed = true;
+            return Some(Err(error));
+        }
+        if self.predicate.as_ref().is_some_and(|p| p.panicked) {
+            self.failed = true;
+            return Some(Err(Error::Outcome(
+                OTIO_ErrorStatus_Outcome__OTIO_ErrorStatus_Outcome_INTERNAL_ERROR
+                    as OTIO_ErrorStatus_Outcome,
+            )));
+        }
+        (!child.is_null()).then_some(Ok(child as *mut T))
+    }
+}
+
+impl<'a, T> Drop for FindChildren<'a, T> {
+    fn drop(&mut self) {
+        if !self.search.is_null() {
+            unsafe { ComposableSearch_destroy(self.search) }
+        }
+    }
+}
+
+/// Lazily finds the children of `root` of type `T`, optionally limited to
+/// those overlapping `search_range` in the root's own time, and to direct
+/// children when `shallow_search` is set.
+pub fn find_children<'a, T: Schema, R: SearchRoot>(
+    root: &'a R,
+    search_range: Option<TimeRange>,
+    shallow_search: bool,
+) -> FindChildren<'a, T> {
+    FindChildren::new(
+        root,
+        Some(T::SCHEMA_NAME),
+        search_range,
+        shallow_search,
+        None,
+    )
+}
+
+/// Lazily finds every clip below `root`.
+pub fn find_clips<R: SearchRoot>(
+    root: &R,
+    search_range: Option<TimeRange>,
+) -> FindChildren<'_, Clip> {
+    find_children::<Clip, R>(root, search_range, false)
+}
+
+/// Lazily finds the children of `root` for which `predicate` returns true.
+pub fn find_if<'a, R: SearchRoot>(
+    root: &'a R,
+    search_range: Option<TimeRange>,
+    shallow_search: bool,
+    predicate: impl FnMut(*mut Composable) -> bool + 'a,
+) -> FindChildren<'a, Composable> {
+    FindChildren::new(
+        root,
+        None,
+        search_range,
+        shallow_search,
+        Some(Box::new(predicate)),
+    )
+}
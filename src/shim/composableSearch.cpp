@@ -0,0 +1,169 @@
+// Lazy pre-order search over the children of a timeline or composition.
+
+#include "shim.h"
+
+#include <opentimelineio/composition.h>
+#include <opentimelineio/serializableCollection.h>
+#include <opentimelineio/stack.h>
+#include <opentimelineio/timeline.h>
+
+#include "copentimelineio/composableSearch.h"
+
+#include <string>
+#include <vector>
+
+namespace {
+
+struct Frame {
+    std::vector<otio::SerializableObject *> children;
+    size_t next = 0;
+    // Set when children belong to a composition, so that they can be
+    // filtered against search_range, which is then in its coordinates.
+    otio::Composition *composition = nullptr;
+    otio::optional<otio::TimeRange> search_range;
+};
+
+bool intersects(otio::TimeRange const &lhs, otio::TimeRange const &rhs) {
+    return lhs.start_time() < rhs.end_time_exclusive() &&
+           rhs.start_time() < lhs.end_time_exclusive();
+}
+
+bool has_schema(otio::SerializableObject *object, std::string const &schema_name) {
+    if (schema_name == "Composable") {
+        return dynamic_cast<otio::Composable *>(object) != nullptr;
+    }
+    if (schema_name == "Item") {
+        return dynamic_cast<otio::Item *>(object) != nullptr;
+    }
+    if (schema_name == "Composition") {
+        return dynamic_cast<otio::Composition *>(object) != nullptr;
+    }
+    return object->schema_name() == schema_name;
+}
+
+Frame composition_frame(
+        otio::Composition *composition, otio::optional<otio::TimeRange> search_range) {
+    Frame frame;
+    for (auto const &child : composition->children()) {
+        frame.children.push_back(child.value);
+    }
+    frame.composition = composition;
+    frame.search_range = search_range;
+    return frame;
+}
+
+} // namespace
+
+struct ComposableSearch {
+    std::vector<Frame> frames;
+    bool has_schema_name = false;
+    std::string schema_name;
+    bool shallow_search = false;
+    ComposablePredicate predicate = nullptr;
+    void *user_data = nullptr;
+
+    // Queues the children of object, if it has any, to be visited next.
+    // search_range is expressed in the coordinates of parent_composition.
+    bool descend(
+            otio::SerializableObject *object,
+            otio::Composition *parent_composition,
+            otio::optional<otio::TimeRange> search_range,
+            otio::ErrorStatus *error_status) {
+        if (auto composition = dynamic_cast<otio::Composition *>(object)) {
+            if (search_range && parent_composition) {
+                search_range = parent_composition->transformed_time_range(
+                        *search_range, composition, error_status);
+                if (otio::is_error(error_status)) {
+                    return false;
+                }
+            }
+            frames.push_back(composition_frame(composition, search_range));
+        } else if (auto timeline = dynamic_cast<otio::Timeline *>(object)) {
+            frames.push_back(composition_frame(timeline->tracks(), search_range));
+        } else if (auto collection = dynamic_cast<otio::SerializableCollection *>(object)) {
+            Frame frame;
+            for (auto const &child : collection->children()) {
+                frame.children.push_back(child.value);
+            }
+            frame.search_range = search_range;
+            frames.push_back(frame);
+        }
+        return true;
+    }
+
+    bool matches(otio::Composable *composable) {
+        if (has_schema_name && !has_schema(composable, schema_name)) {
+            return false;
+        }
+        return !predicate ||
+               predicate(shim_cast<Composable>(composable), user_data);
+    }
+};
+
+ComposableSearch *ComposableSearch_create(
+        OTIOSerializableObject *root,
+        const char *schema_name,
+        OptionalTimeRange search_range,
+        bool shallow_search,
+        ComposablePredicate predicate,
+        void *user_data,
+        OTIOErrorStatus *error_status) {
+    auto search = new ComposableSearch;
+    search->has_schema_name = schema_name != nullptr;
+    search->schema_name = schema_name ? schema_name : "";
+    search->shallow_search = shallow_search;
+    search->predicate = predicate;
+    search->user_data = user_data;
+
+    if (!search->descend(
+                shim_cast<otio::SerializableObject>(root),
+                nullptr,
+                shim_optional_range(search_range),
+                shim_cast<otio::ErrorStatus>(error_status))) {
+        delete search;
+        return nullptr;
+    }
+    return search;
+}
+
+Composable *
+ComposableSearch_next(ComposableSearch *self, OTIOErrorStatus *error_status) {
+    auto status = shim_cast<otio::ErrorStatus>(error_status);
+    while (!self->frames.empty()) {
+        Frame &frame = self->frames.back();
+        if (frame.next >= frame.children.size()) {
+            self->frames.pop_back();
+            continue;
+        }
+        size_t index = frame.next++;
+        auto child = frame.children[index];
+        if (frame.composition && frame.search_range) {
+            auto child_range =
+                    frame.composition->range_of_child_at_index(int(index), status);
+            if (otio::is_error(status)) {
+                self->frames.clear();
+                return nullptr;
+            }
+            if (!intersects(child_range, *frame.search_range)) {
+                continue;
+            }
+        }
+        auto composable = dynamic_cast<otio::Composable *>(child);
+        if (!self->shallow_search) {
+            auto parent_composition = frame.composition;
+            auto search_range = frame.search_range;
+            if (!self->descend(child, parent_composition, search_range, status)) {
+                self->frames.clear();
+                return nullptr;
+            }
+        }
+        if (composable && self->matches(composable)) {
+            return shim_cast<Composable>(composable);
+        }
+    }
+    return nullptr;
+}
+
+bool ComposableSearch_done(ComposableSearch *self) { return self->frames.empty(); }
+
+void ComposableSearch_destroy(ComposableSearch *self) { delete self; }
@@ -0,0 +1,200 @@
+// List-like mutation of the effects and markers of an item.
+
+#include "shim.h"
+
+#include <opentimelineio/item.h>
+
+#include "copentimelineio/clip.h"
+#include "copentimelineio/composition.h"
+#include "copentimelineio/gap.h"
+#include "copentimelineio/item.h"
+#include "copentimelineio/stack.h"
+#include "copentimelineio/track.h"
+
+#include <algorithm>
+#include <vector>
+
+namespace {
+
+template <typename T>
+using RetainerList = std::vector<otio::SerializableObject::Retainer<T>>;
+
+bool valid_index(int index, size_t end, OTIOErrorStatus *error_status) {
+    if (index < 0 || size_t(index) >= end) {
+        shim_set_error(error_status, otio::ErrorStatus::ILLEGAL_INDEX, "index out of range");
+        return false;
+    }
+    return true;
+}
+
+template <typename T>
+bool insert_at(
+        RetainerList<T> &list, int index, T *value, OTIOErrorStatus *error_status) {
+    if (!value) {
+        shim_set_error(
+                error_status, otio::ErrorStatus::TYPE_MISMATCH, "cannot insert a null object");
+        return false;
+    }
+    if (!valid_index(index, list.size() + 1, error_status)) {
+        return false;
+    }
+    list.insert(list.begin() + index, otio::SerializableObject::Retainer<T>(value));
+    return true;
+}
+
+template <typename T>
+T *remove_at(RetainerList<T> &list, int index, OTIOErrorStatus *error_status) {
+    if (!valid_index(index, list.size(), error_status)) {
+        return nullptr;
+    }
+    T *value = list[size_t(index)].take_value();
+    list.erase(list.begin() + index);
+    return value;
+}
+
+template <typename T>
+bool move(RetainerList<T> &list, int from_index, int to_index, OTIOErrorStatus *error_status) {
+    if (!valid_index(from_index, list.size(), error_status) ||
+        !valid_index(to_index, list.size(), error_status)) {
+        return false;
+    }
+    auto first = list.begin();
+    if (from_index < to_index) {
+        std::rotate(first + from_index, first + from_index + 1, first + to_index + 1);
+    } else {
+        std::rotate(first + to_index, first + from_index, first + from_index + 1);
+    }
+    return true;
+}
+
+template <typename T, typename C, typename Predicate>
+int retain(RetainerList<T> &list, Predicate predicate, void *user_data) {
+    size_t size = list.size();
+    list.erase(
+            std::remove_if(
+                    list.begin(),
+                    list.end(),
+                    [&](otio::SerializableObject::Retainer<T> const &value) {
+                        return !predicate(shim_cast<C>(value.value), user_data);
+                    }),
+            list.end());
+    return int(size - list.size());
+}
+
+otio::Item *item(void *self) { return shim_cast<otio::Item>(self); }
+
+bool append_effect(void *self, Effect *effect, OTIOErrorStatus *error_status) {
+    auto &effects = item(self)->effects();
+    return insert_at(effects, int(effects.size()), shim_cast<otio::Effect>(effect), error_status);
+}
+
+bool insert_effect(void *self, int index, Effect *effect, OTIOErrorStatus *error_status) {
+    return insert_at(item(self)->effects(), index, shim_cast<otio::Effect>(effect), error_status);
+}
+
+Effect *remove_effect(void *self, int index, OTIOErrorStatus *error_status) {
+    return shim_cast<Effect>(remove_at(item(self)->effects(), index, error_status));
+}
+
+bool move_effect(void *self, int from_index, int to_index, OTIOErrorStatus *error_status) {
+    return move(item(self)->effects(), from_index, to_index, error_status);
+}
+
+void clear_effects(void *self) { item(self)->effects().clear(); }
+
+int retain_effects(void *self, EffectPredicate predicate, void *user_data) {
+    return retain<otio::Effect, Effect>(item(self)->effects(), predicate, user_data);
+}
+
+bool append_marker(void *self, Marker *marker, OTIOErrorStatus *error_status) {
+    auto &markers = item(self)->markers();
+    return insert_at(markers, int(markers.size()), shim_cast<otio::Marker>(marker), error_status);
+}
+
+bool insert_marker(void *self, int index, Marker *marker, OTIOErrorStatus *error_status) {
+    return insert_at(item(self)->markers(), index, shim_cast<otio::Marker>(marker), error_status);
+}
+
+Marker *remove_marker(void *self, int index, OTIOErrorStatus *error_status) {
+    return shim_cast<Marker>(remove_at(item(self)->markers(), index, error_status));
+}
+
+bool move_marker(void *self, int from_index, int to_index, OTIOErrorStatus *error_status) {
+    return move(item(self)->markers(), from_index, to_index, error_status);
+}
+
+void clear_markers(void *self) { item(self)->markers().clear(); }
+
+int retain_markers(void *self, MarkerPredicate predicate, void *user_data) {
+    return retain<otio::Marker, Marker>(item(self)->markers(), predicate, user_data);
+}
+
+// Orders markers by the start of their marked range, keeping the relative
+// order of markers that start together.
+void sort_markers(void *self) {
+    auto &markers = item(self)->markers();
+    std::stable_sort(
+            markers.begin(),
+            markers.end(),
+            [](otio::SerializableObject::Retainer<otio::Marker> const &lhs,
+               otio::SerializableObject::Retainer<otio::Marker> const &rhs) {
+                return lhs.value->marked_range().start_time() <
+                       rhs.value->marked_range().start_time();
+            });
+}
+
+} // namespace
+
+// Defines, for TYPE, the C entry points declared next to TYPE##_effects and
+// TYPE##_markers in its header:
+//   TYPE##_append_effect, TYPE##_insert_effect, TYPE##_remove_effect,
+//   TYPE##_move_effect, TYPE##_clear_effects, TYPE##_retain_effects,
+//   TYPE##_append_marker, TYPE##_insert_marker, TYPE##_remove_marker,
+//   TYPE##_move_marker, TYPE##_clear_markers, TYPE##_retain_markers and
+//   TYPE##_sort_markers.
+// The clear, retain and sort variants cannot fail and take no error status.
+#define DEFINE_EFFECT_AND_MARKER_MUTATORS(TYPE)                                            \
+    bool TYPE##_append_effect(TYPE *self, Effect *effect, OTIOErrorStatus *error_status) { \
+        return append_effect(self, effect, error_status);                                  \
+    }                                                                                      \
+    bool TYPE##_insert_effect(                                                             \
+            TYPE *self, int index, Effect *effect, OTIOErrorStatus *error_status) {         \
+        return insert_effect(self, index, effect, error_status);                           \
+    }                                                                                      \
+    Effect *TYPE##_remove_effect(TYPE *self, int index, OTIOErrorStatus *error_status) {  \
+        return remove_effect(self, index, error_status);                                   \
+    }                                                                                      \
+    bool TYPE##_move_effect(                                                               \
+            TYPE *self, int from_index, int to_index, OTIOErrorStatus *error_status) {      \
+        return move_effect(self, from_index, to_index, error_status);                      \
+    }                                                                                      \
+    void TYPE##_clear_effects(TYPE *self) { clear_effects(self); }                        \
+    int TYPE##_retain_effects(TYPE *self, EffectPredicate predicate, void *user_data) {   \
+        return retain_effects(self, predicate, user_data);                                 \
+    }                                                                                      \
+    bool TYPE##_append_marker(TYPE *self, Marker *marker, OTIOErrorStatus *error_status) { \
+        return append_marker(self, marker, error_status);                                  \
+    }                                                                                      \
+    bool TYPE##_insert_marker(                                                             \
+            TYPE *self, int index, Marker *marker, OTIOErrorStatus *error_status) {         \
+        return insert_marker(self, index, marker, error_status);                           \
+    }                                                                                      \
+    Marker *TYPE##_remove_marker(TYPE *self, int index, OTIOErrorStatus *error_status) {  \
+        return remove_marker(self, index, error_status);                                   \
+    }                                                                                      \
+    bool TYPE##_move_marker(                                                               \
+            TYPE *self, int from_index, int to_index, OTIOErrorStatus *error_status) {      \
+        return move_marker(self, from_index, to_index, error_status);                      \
+    }                                                                                      \
+    void TYPE##_clear_markers(TYPE *self) { clear_markers(self); }                        \
+    int TYPE##_retain_markers(TYPE *self, MarkerPredicate predicate, void *user_data) {   \
+        return retain_markers(self, predicate, user_data);                                 \
+    }                                                                                      \
+    void TYPE##_sort_markers(TYPE *self) { sort_markers(self); }
+
+DEFINE_EFFECT_AND_MARKER_MUTATORS(Item)
+DEFINE_EFFECT_AND_MARKER_MUTATORS(Clip)
+DEFINE_EFFECT_AND_MARKER_MUTATORS(Gap)
+DEFINE_EFFECT_AND_MARKER_MUTATORS(Composition)
+DEFINE_EFFECT_AND_MARKER_MUTATORS(Stack)
+DEFINE_EFFECT_AND_MARKER_MUTATORS(Track)
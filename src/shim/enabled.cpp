@@ -0,0 +1,151 @@
+// Item::enabled accessors and the skip-disabled variants of each_clip and
+// flatten_stack.
+
+#include "shim.h"
+
+#include <opentimelineio/clip.h>
+#include <opentimelineio/gap.h>
+#include <opentimelineio/stack.h>
+#include <opentimelineio/track.h>
+
+#include "copentimelineio/clip.h"
+#include "copentimelineio/composableVector.h"
+#include "copentimelineio/composition.h"
+#include "copentimelineio/gap.h"
+#include "copentimelineio/item.h"
+#include "copentimelineio/stack.h"
+#include "copentimelineio/stackAlgorithm.h"
+#include "copentimelineio/track.h"
+#include "copentimelineio/trackVector.h"
+
+#include <vector>
+
+namespace {
+
+bool item_enabled(void *self) {
+    return shim_cast<otio::Item>(self)->enabled();
+}
+
+void item_set_enabled(void *self, bool enabled) {
+    shim_cast<otio::Item>(self)->set_enabled(enabled);
+}
+
+// The direct clip children of track, like Track_each_clip.
+void collect_clips(otio::Track *track, ComposableVector *clips, bool skip_disabled) {
+    for (auto const &child : track->children()) {
+        auto clip = dynamic_cast<otio::Clip *>(child.value);
+        if (clip && (!skip_disabled || clip->enabled())) {
+            ComposableVector_push_back(clips, shim_cast<Composable>(clip));
+        }
+    }
+}
+
+// Replaces every disabled item below composition with a gap of the same
+// duration, so that lower tracks show through when flattening.
+bool replace_disabled_with_gaps(
+        otio::Composition *composition, otio::ErrorStatus *error_status) {
+    auto const &children = composition->children();
+    for (size_t index = 0; index < children.size(); index++) {
+        auto item = dynamic_cast<otio::Item *>(children[index].value);
+        if (!item) {
+            continue;
+        }
+        if (!item->enabled()) {
+            auto range = item->trimmed_range(error_status);
+            if (otio::is_error(error_status)) {
+                return false;
+            }
+            otio::SerializableObject::Retainer<otio::Gap> gap(new otio::Gap(otio::TimeRange(
+                    otio::RationalTime(0, range.duration().rate()), range.duration())));
+            if (!composition->set_child(int(index), gap.value, error_status)) {
+                return false;
+            }
+        } else if (auto nested = dynamic_cast<otio::Composition *>(item)) {
+            if (!replace_disabled_with_gaps(nested, error_status)) {
+                return false;
+            }
+        }
+    }
+    return true;
+}
+
+Track *flatten_enabled_tracks(
+        std::vector<otio::Track *> const &tracks, OTIOErrorStatus *error_status) {
+    auto status = shim_cast<otio::ErrorStatus>(error_status);
+    std::vector<otio::SerializableObject::Retainer<otio::Track>> copies;
+    TrackVector *enabled = TrackVector_create();
+    for (auto track : tracks) {
+        if (!track->enabled()) {
+            continue;
+        }
+        auto copy = dynamic_cast<otio::Track *>(track->clone(status));
+        if (!copy) {
+            TrackVector_destroy(enabled);
+            return nullptr;
+        }
+        copies.emplace_back(copy);
+        if (!replace_disabled_with_gaps(copy, status)) {
+            TrackVector_destroy(enabled);
+            return nullptr;
+        }
+        TrackVector_push_back(enabled, shim_cast<Track>(copy));
+    }
+    Track *flattened = flatten_stack_track_vector(enabled, error_status);
+    TrackVector_destroy(enabled);
+    return flattened;
+}
+
+} // namespace
+
+bool Item_enabled(Item *self) { return item_enabled(self); }
+void Item_set_enabled(Item *self, bool enabled) { item_set_enabled(self, enabled); }
+bool Clip_enabled(Clip *self) { return item_enabled(self); }
+void Clip_set_enabled(Clip *self, bool enabled) { item_set_enabled(self, enabled); }
+bool Gap_enabled(Gap *self) { return item_enabled(self); }
+void Gap_set_enabled(Gap *self, bool enabled) { item_set_enabled(self, enabled); }
+bool Composition_enabled(Composition *self) { return item_enabled(self); }
+void Composition_set_enabled(Composition *self, bool enabled) {
+    item_set_enabled(self, enabled);
+}
+bool Stack_enabled(Stack *self) { return item_enabled(self); }
+void Stack_set_enabled(Stack *self, bool enabled) { item_set_enabled(self, enabled); }
+bool Track_enabled(Track *self) { return item_enabled(self); }
+void Track_set_enabled(Track *self, bool enabled) { item_set_enabled(self, enabled); }
+
+ComposableVector *Track_each_clip_with_options(Track *self, bool skip_disabled) {
+    ComposableVector *clips = ComposableVector_create();
+    collect_clips(shim_cast<otio::Track>(self), clips, skip_disabled);
+    return clips;
+}
+
+Track *flatten_stack_with_options(
+        Stack *in_stack, bool skip_disabled, OTIOErrorStatus *error_status) {
+    if (!skip_disabled) {
+        return flatten_stack(in_stack, error_status);
+    }
+    std::vector<otio::Track *> tracks;
+    for (auto const &child : shim_cast<otio::Stack>(in_stack)->children()) {
+        auto track = dynamic_cast<otio::Track *>(child.value);
+        if (!track) {
+            shim_set_error(
+                    error_status,
+                    otio::ErrorStatus::TYPE_MISMATCH,
+                    "expected item of type Track*");
+            return nullptr;
+        }
+        tracks.push_back(track);
+    }
+    return flatten_enabled_tracks(tracks, error_status);
+}
+
+Track *flatten_stack_track_vector_with_options(
+        TrackVector *tracks, bool skip_disabled, OTIOErrorStatus *error_status) {
+    if (!skip_disabled) {
+        return flatten_stack_track_vector(tracks, error_status);
+    }
+    std::vector<otio::Track *> track_list;
+    for (int index = 0; index < TrackVector_size(tracks); index++) {
+        track_list.push_back(shim_cast<otio::Track>(TrackVector_at(tracks, index)));
+    }
+    return flatten_enabled_tracks(track_list, error_status);
+}
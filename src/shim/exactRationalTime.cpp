@@ -0,0 +1,337 @@
+// Exact rational time declared in exactRationalTime.h.
+
+#include "opentime.h"
+
+#include "copentime/exactRationalTime.h"
+
+#include <cmath>
+#include <cstdint>
+#include <limits>
+
+namespace {
+
+using wide = __int128;
+
+ExactRational const invalid_rational{0, 0};
+
+// Rates within this distance of n*1000/1001, for one of the nominal rates
+// below, are taken to be NTSC.
+double const ntsc_tolerance = 0.001;
+
+double const ntsc_nominal_rates[] = {24, 30, 48, 60, 120, 240};
+
+bool is_ntsc_nominal_rate(double nominal) {
+    for (double ntsc_nominal : ntsc_nominal_rates) {
+        if (nominal == ntsc_nominal) {
+            return true;
+        }
+    }
+    return false;
+}
+
+// Continued fractions for values stop at this denominator.
+int64_t const max_value_denominator = int64_t(1) << 32;
+
+wide gcd(wide a, wide b) {
+    a = a < 0 ? -a : a;
+    b = b < 0 ? -b : b;
+    while (b != 0) {
+        wide rest = a % b;
+        a = b;
+        b = rest;
+    }
+    return a;
+}
+
+bool fits(wide value) {
+    return value >= std::numeric_limits<int64_t>::min() &&
+           value <= std::numeric_limits<int64_t>::max();
+}
+
+// Reduces numerator/denominator; fails if the denominator is zero or the
+// reduced fraction does not fit in int64.
+bool reduce(wide numerator, wide denominator, ExactRational *result) {
+    if (denominator == 0) {
+        return false;
+    }
+    if (denominator < 0) {
+        numerator = -numerator;
+        denominator = -denominator;
+    }
+    wide divisor = gcd(numerator, denominator);
+    if (divisor > 1) {
+        numerator /= divisor;
+        denominator /= divisor;
+    }
+    if (!fits(numerator) || !fits(denominator)) {
+        return false;
+    }
+    *result = ExactRational{int64_t(numerator), int64_t(denominator)};
+    return true;
+}
+
+bool is_valid(ExactRational value) { return value.denominator != 0; }
+
+bool is_valid_rate(ExactRational rate) { return rate.denominator != 0 && rate.numerator > 0; }
+
+bool multiply(ExactRational lhs, ExactRational rhs, ExactRational *result) {
+    return reduce(
+            wide(lhs.numerator) * rhs.numerator, wide(lhs.denominator) * rhs.denominator, result);
+}
+
+bool divide(ExactRational lhs, ExactRational rhs, ExactRational *result) {
+    return reduce(
+            wide(lhs.numerator) * rhs.denominator, wide(lhs.denominator) * rhs.numerator, result);
+}
+
+bool add(ExactRational lhs, ExactRational rhs, ExactRational *result) {
+    return reduce(
+            wide(lhs.numerator) * rhs.denominator + wide(rhs.numerator) * lhs.denominator,
+            wide(lhs.denominator) * rhs.denominator,
+            result);
+}
+
+wide floor_divide(wide numerator, wide denominator) {
+    wide quotient = numerator / denominator;
+    return quotient * denominator > numerator ? quotient - 1 : quotient;
+}
+
+// Compares lhs_numerator/lhs_denominator with rhs_numerator/rhs_denominator
+// through their continued fractions, so the products never need more than
+// 128 bits. Both denominators must be positive.
+int compare(wide lhs_numerator, wide lhs_denominator, wide rhs_numerator, wide rhs_denominator) {
+    for (;;) {
+        wide lhs_whole = floor_divide(lhs_numerator, lhs_denominator);
+        wide rhs_whole = floor_divide(rhs_numerator, rhs_denominator);
+        if (lhs_whole != rhs_whole) {
+            return lhs_whole < rhs_whole ? -1 : 1;
+        }
+        wide lhs_rest = lhs_numerator - lhs_whole * lhs_denominator;
+        wide rhs_rest = rhs_numerator - rhs_whole * rhs_denominator;
+        if (lhs_rest == 0 || rhs_rest == 0) {
+            return lhs_rest == rhs_rest ? 0 : (lhs_rest == 0 ? -1 : 1);
+        }
+        // lhs_rest/lhs_denominator < rhs_rest/rhs_denominator exactly when
+        // rhs_denominator/rhs_rest < lhs_denominator/lhs_rest.
+        wide next_lhs_numerator = rhs_denominator;
+        wide next_lhs_denominator = rhs_rest;
+        rhs_numerator = lhs_denominator;
+        rhs_denominator = lhs_rest;
+        lhs_numerator = next_lhs_numerator;
+        lhs_denominator = next_lhs_denominator;
+    }
+}
+
+int compare(ExactRational lhs, ExactRational rhs) {
+    return compare(lhs.numerator, lhs.denominator, rhs.numerator, rhs.denominator);
+}
+
+// The value of time rescaled to rate, or false on overflow.
+bool value_at(ExactRationalTime time, ExactRational rate, ExactRational *result) {
+    ExactRational seconds;
+    return divide(time.value, time.rate, &seconds) && multiply(seconds, rate, result);
+}
+
+// Finds the fraction closest to value by continued fractions, stopping once
+// it converts back to exactly value.
+bool rational_from_double(double value, ExactRational *result) {
+    if (!std::isfinite(value) || std::fabs(value) >= 9.2e18) {
+        return false;
+    }
+    double whole = std::floor(value);
+    int64_t previous_numerator = 1, numerator = int64_t(whole);
+    int64_t previous_denominator = 0, denominator = 1;
+    double rest = value - whole;
+    while (double(numerator) / double(denominator) != value) {
+        if (rest == 0) {
+            break;
+        }
+        double inverse = 1 / rest;
+        double term = std::floor(inverse);
+        rest = inverse - term;
+        if (term >= double(max_value_denominator)) {
+            break;
+        }
+        wide next_numerator = wide(int64_t(term)) * numerator + previous_numerator;
+        wide next_denominator = wide(int64_t(term)) * denominator + previous_denominator;
+        if (next_denominator > max_value_denominator || !fits(next_numerator)) {
+            break;
+        }
+        previous_numerator = numerator;
+        previous_denominator = denominator;
+        numerator = int64_t(next_numerator);
+        denominator = int64_t(next_denominator);
+    }
+    if (double(numerator) / double(denominator) != value) {
+        return false;
+    }
+    return reduce(numerator, denominator, result);
+}
+
+ExactRationalTime invalid_time() { return ExactRationalTime{invalid_rational, invalid_rational}; }
+
+} // namespace
+
+ExactRational ExactRational_create(
+        int64_t numerator, int64_t denominator, OpenTimeErrorStatus *error_status) {
+    ExactRational result;
+    if (denominator == 0) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "denominator is zero");
+        return invalid_rational;
+    }
+    if (!reduce(numerator, denominator, &result)) {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_OVERFLOW,
+                "fraction does not fit in int64");
+        return invalid_rational;
+    }
+    return result;
+}
+
+ExactRational ExactRational_from_rate(double rate) {
+    if (!std::isfinite(rate) || std::fabs(rate) >= 9.2e15) {
+        return invalid_rational;
+    }
+    ExactRational result;
+    if (rate == std::floor(rate)) {
+        reduce(int64_t(rate), 1, &result);
+        return result;
+    }
+    double nominal = std::round(rate * 1.001);
+    if (is_ntsc_nominal_rate(nominal) &&
+        std::fabs(rate - nominal * 1000 / 1001) < ntsc_tolerance) {
+        reduce(wide(int64_t(nominal)) * 1000, 1001, &result);
+        return result;
+    }
+    reduce(int64_t(std::round(rate * 1000)), 1000, &result);
+    return result;
+}
+
+double ExactRational_to_double(ExactRational self) {
+    if (!is_valid(self)) {
+        return std::nan("");
+    }
+    return double(self.numerator) / double(self.denominator);
+}
+
+bool ExactRational_is_ntsc_rate(ExactRational self) {
+    ExactRational reduced;
+    return reduce(self.numerator, self.denominator, &reduced) && reduced.denominator == 1001 &&
+           reduced.numerator % 1000 == 0;
+}
+
+ExactRationalTime ExactRationalTime_create(ExactRational value, ExactRational rate) {
+    ExactRationalTime result{};
+    if (!reduce(value.numerator, value.denominator, &result.value) ||
+        !reduce(rate.numerator, rate.denominator, &result.rate)) {
+        return invalid_time();
+    }
+    return result;
+}
+
+ExactRationalTime ExactRationalTime_from_frames(int64_t frames, ExactRational rate) {
+    return ExactRationalTime_create(ExactRational{frames, 1}, rate);
+}
+
+ExactRationalTime ExactRationalTime_from_rational_time(
+        RationalTime rational_time, OpenTimeErrorStatus *error_status) {
+    ExactRationalTime result{};
+    result.rate = ExactRational_from_rate(rational_time.rate);
+    if (!is_valid_rate(result.rate)) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "rate is not positive");
+        return invalid_time();
+    }
+    if (!rational_from_double(rational_time.value, &result.value)) {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_NOT_REPRESENTABLE,
+                "value cannot be represented exactly");
+        return invalid_time();
+    }
+    return result;
+}
+
+RationalTime ExactRationalTime_to_rational_time(ExactRationalTime self) {
+    return RationalTime{ExactRational_to_double(self.value), ExactRational_to_double(self.rate)};
+}
+
+bool ExactRationalTime_is_invalid_time(ExactRationalTime self) {
+    return !is_valid(self.value) || !is_valid_rate(self.rate);
+}
+
+ExactRationalTime ExactRationalTime_rescaled_to(ExactRationalTime self, ExactRational new_rate) {
+    ExactRationalTime result{};
+    if (ExactRationalTime_is_invalid_time(self) ||
+        !reduce(new_rate.numerator, new_rate.denominator, &result.rate) ||
+        !is_valid_rate(result.rate) || !value_at(self, result.rate, &result.value)) {
+        return invalid_time();
+    }
+    return result;
+}
+
+ExactRational ExactRationalTime_to_seconds(ExactRationalTime self) {
+    ExactRational result;
+    if (ExactRationalTime_is_invalid_time(self) || !divide(self.value, self.rate, &result)) {
+        return invalid_rational;
+    }
+    return result;
+}
+
+namespace {
+
+ExactRationalTime
+add_times(ExactRationalTime lhs, ExactRationalTime rhs, int sign, OpenTimeErrorStatus *error_status) {
+    if (ExactRationalTime_is_invalid_time(lhs) || ExactRationalTime_is_invalid_time(rhs)) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "time is invalid");
+        return invalid_time();
+    }
+    ExactRationalTime result{};
+    result.rate = compare(lhs.rate, rhs.rate) < 0 ? rhs.rate : lhs.rate;
+    ExactRational left, right;
+    if (!value_at(lhs, result.rate, &left) || !value_at(rhs, result.rate, &right) ||
+        !add(left, ExactRational{sign * right.numerator, right.denominator}, &result.value)) {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_OVERFLOW,
+                "result does not fit in int64");
+        return invalid_time();
+    }
+    return result;
+}
+
+} // namespace
+
+ExactRationalTime ExactRationalTime_add(
+        ExactRationalTime lhs, ExactRationalTime rhs, OpenTimeErrorStatus *error_status) {
+    return add_times(lhs, rhs, 1, error_status);
+}
+
+ExactRationalTime ExactRationalTime_subtract(
+        ExactRationalTime lhs, ExactRationalTime rhs, OpenTimeErrorStatus *error_status) {
+    return add_times(lhs, rhs, -1, error_status);
+}
+
+int ExactRationalTime_compare(ExactRationalTime lhs, ExactRationalTime rhs) {
+    bool lhs_invalid = ExactRationalTime_is_invalid_time(lhs);
+    bool rhs_invalid = ExactRationalTime_is_invalid_time(rhs);
+    if (lhs_invalid || rhs_invalid) {
+        return int(rhs_invalid) - int(lhs_invalid);
+    }
+    return compare(
+            wide(lhs.value.numerator) * lhs.rate.denominator,
+            wide(lhs.value.denominator) * lhs.rate.numerator,
+            wide(rhs.value.numerator) * rhs.rate.denominator,
+            wide(rhs.value.denominator) * rhs.rate.numerator);
+}
+
+bool ExactRationalTime_equal(ExactRationalTime lhs, ExactRationalTime rhs) {
+    return ExactRationalTime_compare(lhs, rhs) == 0;
+}
+
+bool ExactRationalTime_not_equal(ExactRationalTime lhs, ExactRationalTime rhs) {
+    return !ExactRationalTime_equal(lhs, rhs);
+}
@@ -0,0 +1,79 @@
+#pragma once
+
+// Helpers for the CSV and JSON exports. Exported strings are malloc'd, so
+// that C callers can free them.
+
+#include <cmath>
+#include <cstdlib>
+#include <cstring>
+#include <iomanip>
+#include <locale>
+#include <sstream>
+#include <string>
+
+inline char *copy_string(std::string const &value) {
+    auto copy = static_cast<char *>(malloc(value.size() + 1));
+    memcpy(copy, value.c_str(), value.size() + 1);
+    return copy;
+}
+
+// Writes whole numbers, such as frame counts, as integers and anything else
+// with the fewest digits that read back as the same double, so that 1987200
+// and 23.976 are not written as 1.9872e+06 and 23.975999999999999.
+inline std::string format_number(double value) {
+    std::ostringstream stream;
+    stream.imbue(std::locale::classic());
+    if (std::isfinite(value) && value == std::trunc(value) && std::fabs(value) < 1e18) {
+        stream << static_cast<long long>(value);
+        return stream.str();
+    }
+    for (int precision = 15; precision <= 17; precision++) {
+        stream.str("");
+        stream << std::setprecision(precision) << value;
+        if (std::strtod(stream.str().c_str(), nullptr) == value) {
+            break;
+        }
+    }
+    return stream.str();
+}
+
+inline std::string csv_field(std::string const &value) {
+    if (value.find_first_of(",\"\n") == std::string::npos) {
+        return value;
+    }
+    std::string quoted = "\"";
+    for (char c : value) {
+        if (c == '"') {
+            quoted += '"';
+        }
+        quoted += c;
+    }
+    return quoted + "\"";
+}
+
+inline std::string json_string(std::string const &value) {
+    std::ostringstream stream;
+    stream << '"';
+    for (unsigned char c : value) {
+        switch (c) {
+            case '"':
+                stream << "\\\"";
+                break;
+            case '\\':
+                stream << "\\\\";
+                break;
+            case '\n':
+                stream << "\\n";
+                break;
+            default:
+                if (c < 0x20) {
+                    stream << "\\u" << std::hex << std::setw(4) << std::setfill('0') << int(c)
+                           << std::dec;
+                } else {
+                    stream << c;
+                }
+        }
+    }
+    stream << '"';
+    return stream.str();
+}
@@ -0,0 +1,200 @@
+// ImageSequenceReference schema declared in imageSequenceReference.h. The
+// MediaReference and SerializableObject members forward to the upstream C
+// bindings for the base class.
+
+#include "shim.h"
+#include "export.h"
+
+#include <opentimelineio/imageSequenceReference.h>
+
+#include "copentimelineio/imageSequenceReference.h"
+#include "copentimelineio/mediaReference.h"
+
+namespace {
+
+using MissingFramePolicy = otio::ImageSequenceReference::MissingFramePolicy;
+
+otio::ImageSequenceReference *reference(ImageSequenceReference *self) {
+    return shim_cast<otio::ImageSequenceReference>(self);
+}
+
+MediaReference *base(ImageSequenceReference *self) { return shim_cast<MediaReference>(self); }
+
+} // namespace
+
+ImageSequenceReference *ImageSequenceReference_create(
+        const char *target_url_base,
+        const char *name_prefix,
+        const char *name_suffix,
+        int start_frame,
+        int frame_step,
+        double rate,
+        int frame_zero_padding,
+        OTIO_ImageSequenceReference_MissingFramePolicy missing_frame_policy,
+        OptionalTimeRange available_range,
+        AnyDictionary *metadata) {
+    otio::AnyDictionary metadata_dictionary;
+    if (metadata) {
+        metadata_dictionary = *shim_cast<otio::AnyDictionary>(metadata);
+    }
+    return shim_cast<ImageSequenceReference>(new otio::ImageSequenceReference(
+            target_url_base,
+            name_prefix,
+            name_suffix,
+            start_frame,
+            frame_step,
+            rate,
+            frame_zero_padding,
+            MissingFramePolicy(missing_frame_policy),
+            shim_optional_range(available_range),
+            metadata_dictionary));
+}
+
+const char *ImageSequenceReference_target_url_base(ImageSequenceReference *self) {
+    return copy_string(reference(self)->target_url_base());
+}
+
+void ImageSequenceReference_set_target_url_base(
+        ImageSequenceReference *self, const char *target_url_base) {
+    reference(self)->set_target_url_base(target_url_base);
+}
+
+const char *ImageSequenceReference_name_prefix(ImageSequenceReference *self) {
+    return copy_string(reference(self)->name_prefix());
+}
+
+void ImageSequenceReference_set_name_prefix(ImageSequenceReference *self, const char *name_prefix) {
+    reference(self)->set_name_prefix(name_prefix);
+}
+
+const char *ImageSequenceReference_name_suffix(ImageSequenceReference *self) {
+    return copy_string(reference(self)->name_suffix());
+}
+
+void ImageSequenceReference_set_name_suffix(ImageSequenceReference *self, const char *name_suffix) {
+    reference(self)->set_name_suffix(name_suffix);
+}
+
+int ImageSequenceReference_start_frame(ImageSequenceReference *self) {
+    return reference(self)->start_frame();
+}
+
+void ImageSequenceReference_set_start_frame(ImageSequenceReference *self, int start_frame) {
+    reference(self)->set_start_frame(start_frame);
+}
+
+int ImageSequenceReference_frame_step(ImageSequenceReference *self) {
+    return reference(self)->frame_step();
+}
+
+void ImageSequenceReference_set_frame_step(ImageSequenceReference *self, int frame_step) {
+    reference(self)->set_frame_step(frame_step);
+}
+
+double ImageSequenceReference_rate(ImageSequenceReference *self) { return reference(self)->rate(); }
+
+void ImageSequenceReference_set_rate(ImageSequenceReference *self, double rate) {
+    reference(self)->set_rate(rate);
+}
+
+int ImageSequenceReference_frame_zero_padding(ImageSequenceReference *self) {
+    return reference(self)->frame_zero_padding();
+}
+
+void ImageSequenceReference_set_frame_zero_padding(
+        ImageSequenceReference *self, int frame_zero_padding) {
+    reference(self)->set_frame_zero_padding(frame_zero_padding);
+}
+
+OTIO_ImageSequenceReference_MissingFramePolicy
+ImageSequenceReference_missing_frame_policy(ImageSequenceReference *self) {
+    return OTIO_ImageSequenceReference_MissingFramePolicy(reference(self)->missing_frame_policy());
+}
+
+void ImageSequenceReference_set_missing_frame_policy(
+        ImageSequenceReference *self,
+        OTIO_ImageSequenceReference_MissingFramePolicy missing_frame_policy) {
+    reference(self)->set_missing_frame_policy(MissingFramePolicy(missing_frame_policy));
+}
+
+int ImageSequenceReference_end_frame(ImageSequenceReference *self) {
+    return reference(self)->end_frame();
+}
+
+int ImageSequenceReference_number_of_images_in_sequence(ImageSequenceReference *self) {
+    return reference(self)->number_of_images_in_sequence();
+}
+
+int ImageSequenceReference_frame_for_time(
+        ImageSequenceReference *self, RationalTime rational_time, OTIOErrorStatus *error_status) {
+    return reference(self)->frame_for_time(
+            shim_time(rational_time), shim_cast<otio::ErrorStatus>(error_status));
+}
+
+const char *ImageSequenceReference_target_url_for_image_number(
+        ImageSequenceReference *self, int image_number, OTIOErrorStatus *error_status) {
+    return copy_string(reference(self)->target_url_for_image_number(
+            image_number, shim_cast<otio::ErrorStatus>(error_status)));
+}
+
+RationalTime ImageSequenceReference_presentation_time_for_image_number(
+        ImageSequenceReference *self, int image_number, OTIOErrorStatus *error_status) {
+    return shim_time(reference(self)->presentation_time_for_image_number(
+            image_number, shim_cast<otio::ErrorStatus>(error_status)));
+}
+
+OptionalTimeRange ImageSequenceReference_available_range(ImageSequenceReference *self) {
+    return MediaReference_available_range(base(self));
+}
+
+void ImageSequenceReference_set_available_range(
+        ImageSequenceReference *self, OptionalTimeRange available_range) {
+    MediaReference_set_available_range(base(self), available_range);
+}
+
+bool ImageSequenceReference_is_missing_reference(ImageSequenceReference *self) {
+    return MediaReference_is_missing_reference(base(self));
+}
+
+const char *ImageSequenceReference_name(ImageSequenceReference *self) {
+    return MediaReference_name(base(self));
+}
+
+void ImageSequenceReference_set_name(ImageSequenceReference *self, const char *name) {
+    MediaReference_set_name(base(self), name);
+}
+
+AnyDictionary *ImageSequenceReference_metadata(ImageSequenceReference *self) {
+    return MediaReference_metadata(base(self));
+}
+
+bool ImageSequenceReference_to_json_file(
+        ImageSequenceReference *self,
+        const char *file_name,
+        OTIOErrorStatus *error_status,
+        int indent) {
+    return MediaReference_to_json_file(base(self), file_name, error_status, indent);
+}
+
+const char *ImageSequenceReference_to_json_string(
+        ImageSequenceReference *self, OTIOErrorStatus *error_status, int indent) {
+    return MediaReference_to_json_string(base(self), error_status, indent);
+}
+
+bool ImageSequenceReference_is_equivalent_to(
+        ImageSequenceReference *self, OTIOSerializableObject *other) {
+    return MediaReference_is_equivalent_to(base(self), other);
+}
+
+ImageSequenceReference *
+ImageSequenceReference_clone(ImageSequenceReference *self, OTIOErrorStatus *error_status) {
+    return shim_cast<ImageSequenceReference>(MediaReference_clone(base(self), error_status));
+}
+
+const char *ImageSequenceReference_schema_name(ImageSequenceReference *self) {
+    return MediaReference_schema_name(base(self));
+}
+
+int ImageSequenceReference_schema_version(ImageSequenceReference *self) {
+    return MediaReference_schema_version(base(self));
+}
@@ -0,0 +1,265 @@
+// Media relinking declared in mediaLinker.h.
+
+#include "shim.h"
+
+#include <opentimelineio/clip.h>
+#include <opentimelineio/externalReference.h>
+#include <opentimelineio/missingReference.h>
+#include <opentimelineio/serializableCollection.h>
+#include <opentimelineio/timeline.h>
+
+#include "copentimelineio/mediaLinker.h"
+
+#include <cctype>
+#include <cstdio>
+#include <filesystem>
+#include <functional>
+#include <map>
+#include <memory>
+#include <string>
+#include <system_error>
+#include <vector>
+
+struct MediaLinker {
+    std::string name;
+    // Returns the result and, for LINKED, the new reference.
+    std::function<OTIO_MediaLinker_Result(otio::Clip *, otio::MediaReference **)> link;
+    // Called once per relink pass, before the first clip; may be empty.
+    std::function<void()> prepare;
+};
+
+namespace {
+
+otio::ExternalReference *external_reference(otio::Clip *clip) {
+    return dynamic_cast<otio::ExternalReference *>(clip->media_reference());
+}
+
+// A copy of reference pointing at target_url.
+otio::MediaReference *
+relinked(otio::ExternalReference *reference, std::string const &target_url) {
+    return new otio::ExternalReference(
+            target_url, reference->available_range(), reference->metadata());
+}
+
+int hex_digit(char digit) {
+    if (digit >= '0' && digit <= '9') {
+        return digit - '0';
+    }
+    if (digit >= 'a' && digit <= 'f') {
+        return digit - 'a' + 10;
+    }
+    if (digit >= 'A' && digit <= 'F') {
+        return digit - 'A' + 10;
+    }
+    return -1;
+}
+
+// Decodes %XX escapes; malformed escapes are kept as they are.
+std::string percent_decode(std::string const &value) {
+    std::string decoded;
+    for (size_t index = 0; index < value.size(); index++) {
+        if (value[index] == '%' && index + 2 < value.size() &&
+            hex_digit(value[index + 1]) >= 0 && hex_digit(value[index + 2]) >= 0) {
+            decoded += char(hex_digit(value[index + 1]) * 16 + hex_digit(value[index + 2]));
+            index += 2;
+        } else {
+            decoded += value[index];
+        }
+    }
+    return decoded;
+}
+
+// Escapes everything but unreserved characters and the path separators.
+std::string percent_encode(std::string const &path) {
+    std::string encoded;
+    for (unsigned char character : path) {
+        if (std::isalnum(character) || character == '-' || character == '.' ||
+            character == '_' || character == '~' || character == '/') {
+            encoded += char(character);
+        } else {
+            char escape[4];
+            std::snprintf(escape, sizeof(escape), "%%%02X", character);
+            encoded += escape;
+        }
+    }
+    return encoded;
+}
+
+std::string file_url(std::filesystem::path const &path) {
+    auto generic = path.generic_string();
+    // Drive-letter paths need the third slash of file:///C:/...
+    return "file://" + std::string(generic.empty() || generic[0] != '/' ? "/" : "") +
+           percent_encode(generic);
+}
+
+// The decoded last path segment of target_url, without any query or
+// fragment.
+std::string file_name(std::string const &target_url) {
+    auto path = target_url.substr(0, target_url.find_first_of("?#"));
+    auto slash = path.find_last_of('/');
+    return percent_decode(slash == std::string::npos ? path : path.substr(slash + 1));
+}
+
+// Maps each file name under directory to its path; with several files of
+// one name, the first one found wins.
+std::map<std::string, std::filesystem::path>
+index_files(std::filesystem::path const &directory, bool recursive) {
+    namespace fs = std::filesystem;
+    std::map<std::string, fs::path> files;
+    std::error_code error;
+    auto add = [&files](fs::directory_entry const &entry) {
+        std::error_code type_error;
+        if (entry.is_regular_file(type_error)) {
+            files.emplace(entry.path().filename().string(), entry.path());
+        }
+    };
+    if (recursive) {
+        for (fs::recursive_directory_iterator it(directory, error), end; !error && it != end;
+             it.increment(error)) {
+            add(*it);
+        }
+    } else {
+        for (fs::directory_iterator it(directory, error), end; !error && it != end;
+             it.increment(error)) {
+            add(*it);
+        }
+    }
+    return files;
+}
+
+void collect_clips(otio::SerializableObject *object, std::vector<otio::Clip *> &clips) {
+    if (auto clip = dynamic_cast<otio::Clip *>(object)) {
+        clips.push_back(clip);
+    } else if (auto composition = dynamic_cast<otio::Composition *>(object)) {
+        for (auto const &child : composition->children()) {
+            collect_clips(child.value, clips);
+        }
+    } else if (auto collection = dynamic_cast<otio::SerializableCollection *>(object)) {
+        for (auto const &child : collection->children()) {
+            collect_clips(child.value, clips);
+        }
+    } else if (auto timeline = dynamic_cast<otio::Timeline *>(object)) {
+        collect_clips(timeline->tracks(), clips);
+    }
+}
+
+} // namespace
+
+MediaLinker *MediaLinker_create(const char *name, MediaLinkerFunction link, void *user_data) {
+    return new MediaLinker{
+            name, [link, user_data](otio::Clip *clip, otio::MediaReference **new_reference) {
+                auto reference = external_reference(clip);
+                std::string target_url = reference ? reference->target_url() : "";
+                MediaReference *linked = nullptr;
+                auto result = link(
+                        clip->name().c_str(),
+                        shim_cast<AnyDictionary>(&clip->metadata()),
+                        reference ? target_url.c_str() : nullptr,
+                        &linked,
+                        user_data);
+                *new_reference = shim_cast<otio::MediaReference>(linked);
+                return result;
+            },
+            {}};
+}
+
+MediaLinker *MediaLinker_create_directory_search(const char *search_directory, bool recursive) {
+    namespace fs = std::filesystem;
+    std::error_code error;
+    auto directory = fs::absolute(search_directory, error);
+    if (error) {
+        directory = search_directory;
+    }
+    auto files = std::make_shared<std::map<std::string, fs::path>>();
+    return new MediaLinker{
+            "directory_search",
+            [files](otio::Clip *clip, otio::MediaReference **new_reference) {
+                auto reference = external_reference(clip);
+                if (!reference) {
+                    return OTIO_MediaLinker_Result(OTIO_MediaLinker_Result_KEEP);
+                }
+                auto found = files->find(file_name(reference->target_url()));
+                if (found == files->end()) {
+                    return OTIO_MediaLinker_Result(OTIO_MediaLinker_Result_UNRESOLVED);
+                }
+                *new_reference = relinked(reference, file_url(found->second));
+                return OTIO_MediaLinker_Result(OTIO_MediaLinker_Result_LINKED);
+            },
+            [files, directory, recursive] { *files = index_files(directory, recursive); }};
+}
+
+MediaLinker *MediaLinker_create_path_prefix_remap(const char *old_prefix, const char *new_prefix) {
+    std::string from = old_prefix;
+    std::string to = new_prefix;
+    return new MediaLinker{
+            "path_prefix_remap",
+            [from, to](otio::Clip *clip, otio::MediaReference **new_reference) {
+                auto reference = external_reference(clip);
+                if (!reference || reference->target_url().compare(0, from.size(), from) != 0) {
+                    return OTIO_MediaLinker_Result(OTIO_MediaLinker_Result_KEEP);
+                }
+                *new_reference = relinked(reference, to + reference->target_url().substr(from.size()));
+                return OTIO_MediaLinker_Result(OTIO_MediaLinker_Result_LINKED);
+            },
+            {}};
+}
+
+const char *MediaLinker_name(MediaLinker *self) { return self->name.c_str(); }
+
+void MediaLinker_destroy(MediaLinker *self) { delete self; }
+
+bool relink_media(
+        OTIOSerializableObject *root,
+        MediaLinker *linker,
+        bool missing_if_unresolved,
+        RelinkReport *report,
+        OTIOErrorStatus *error_status) {
+    auto object = shim_cast<otio::SerializableObject>(root);
+    if (!dynamic_cast<otio::Clip *>(object) && !dynamic_cast<otio::Composition *>(object) &&
+        !dynamic_cast<otio::SerializableCollection *>(object) &&
+        !dynamic_cast<otio::Timeline *>(object)) {
+        shim_set_error(
+                error_status,
+                otio::ErrorStatus::TYPE_MISMATCH,
+                "root must be a timeline, composition, collection or clip");
+        return false;
+    }
+    std::vector<otio::Clip *> clips;
+    collect_clips(object, clips);
+    if (linker->prepare) {
+        linker->prepare();
+    }
+    RelinkReport counts{};
+    for (auto clip : clips) {
+        otio::MediaReference *new_reference = nullptr;
+        auto result = linker->link(clip, &new_reference);
+        if (result == OTIO_MediaLinker_Result_LINKED && !new_reference) {
+            result = OTIO_MediaLinker_Result_UNRESOLVED;
+        }
+        if (result == OTIO_MediaLinker_Result_LINKED) {
+            if (new_reference == clip->media_reference()) {
+                counts.unchanged++;
+            } else {
+                clip->set_media_reference(new_reference);
+                counts.relinked++;
+            }
+        } else if (result == OTIO_MediaLinker_Result_UNRESOLVED) {
+            counts.missing++;
+            auto old_reference = clip->media_reference();
+            if (missing_if_unresolved && !dynamic_cast<otio::MissingReference *>(old_reference)) {
+                clip->set_media_reference(
+                        old_reference ? new otio::MissingReference(
+                                                old_reference->name(),
+                                                old_reference->available_range(),
+                                                old_reference->metadata())
+                                      : new otio::MissingReference());
+            }
+        } else {
+            counts.unchanged++;
+        }
+    }
+    if (report) {
+        *report = counts;
+    }
+    return true;
+}
@@ -0,0 +1,86 @@
+// Keyed media references on Clip, declared in clip.h.
+
+#include "shim.h"
+#include "export.h"
+
+#include <opentimelineio/clip.h>
+
+#include "copentimelineio/clip.h"
+
+#include <iterator>
+
+const char *Clip_default_media_key = otio::Clip::default_media_key;
+
+namespace {
+
+otio::Clip *clip(Clip *self) { return shim_cast<otio::Clip>(self); }
+
+bool set_media_references(
+        otio::Clip *clip, otio::Clip::MediaReferences const &references, OTIOErrorStatus *error_status) {
+    otio::ErrorStatus status;
+    clip->set_media_references(references, clip->active_media_reference_key(), &status);
+    if (otio::is_error(&status)) {
+        shim_set_error(error_status, status.outcome, status.details);
+        return false;
+    }
+    return true;
+}
+
+} // namespace
+
+const char *Clip_active_media_reference_key(Clip *self) {
+    return copy_string(clip(self)->active_media_reference_key());
+}
+
+bool Clip_set_active_media_reference_key(
+        Clip *self, const char *new_active_key, OTIOErrorStatus *error_status) {
+    otio::ErrorStatus status;
+    clip(self)->set_active_media_reference_key(new_active_key, &status);
+    if (otio::is_error(&status)) {
+        shim_set_error(error_status, status.outcome, status.details);
+        return false;
+    }
+    return true;
+}
+
+int Clip_media_reference_count(Clip *self) { return int(clip(self)->media_references().size()); }
+
+const char *Clip_media_reference_key_at(Clip *self, int index) {
+    auto const &references = clip(self)->media_references();
+    if (index < 0 || size_t(index) >= references.size()) {
+        return nullptr;
+    }
+    return copy_string(std::next(references.begin(), index)->first);
+}
+
+MediaReference *Clip_media_reference_for_key(Clip *self, const char *key) {
+    auto const &references = clip(self)->media_references();
+    auto found = references.find(key);
+    return found == references.end() ? nullptr : shim_cast<MediaReference>(found->second);
+}
+
+bool Clip_set_media_reference_for_key(
+        Clip *self, const char *key, MediaReference *media_reference, OTIOErrorStatus *error_status) {
+    auto references = clip(self)->media_references();
+    references[key] = shim_cast<otio::MediaReference>(media_reference);
+    return set_media_references(clip(self), references, error_status);
+}
+
+bool Clip_remove_media_reference(Clip *self, const char *key, OTIOErrorStatus *error_status) {
+    if (clip(self)->active_media_reference_key() == key) {
+        shim_set_error(
+                error_status,
+                otio::ErrorStatus::MEDIA_REFERENCES_DO_NOT_CONTAIN_ACTIVE_KEY,
+                "the active media reference cannot be removed");
+        return false;
+    }
+    auto references = clip(self)->media_references();
+    if (references.erase(key) == 0) {
+        shim_set_error(
+                error_status,
+                otio::ErrorStatus::KEY_NOT_FOUND,
+                std::string("no media reference for key ") + key);
+        return false;
+    }
+    return set_media_references(clip(self), references, error_status);
+}
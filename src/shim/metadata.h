@@ -0,0 +1,32 @@
+#pragma once
+
+// Metadata comparison shared by the diff and merge shims.
+
+#include "shim.h"
+
+#include <opentimelineio/anyDictionary.h>
+#include <opentimelineio/serializableObjectWithMetadata.h>
+
+#include <string>
+
+// Whether key has the same value, or is missing, in both dictionaries.
+// Values are compared the way OpenTimelineIO compares whole objects, by
+// wrapping each in an otherwise empty object.
+inline bool shim_same_metadata_value(
+        otio::AnyDictionary &lhs_metadata,
+        otio::AnyDictionary &rhs_metadata,
+        std::string const &key) {
+    auto lhs_value = lhs_metadata.find(key);
+    auto rhs_value = rhs_metadata.find(key);
+    if (lhs_value == lhs_metadata.end() || rhs_value == rhs_metadata.end()) {
+        return lhs_value == lhs_metadata.end() && rhs_value == rhs_metadata.end();
+    }
+    otio::AnyDictionary lhs_wrapped, rhs_wrapped;
+    lhs_wrapped[key] = lhs_value->second;
+    rhs_wrapped[key] = rhs_value->second;
+    otio::SerializableObject::Retainer<otio::SerializableObjectWithMetadata> lhs(
+            new otio::SerializableObjectWithMetadata("", lhs_wrapped));
+    otio::SerializableObject::Retainer<otio::SerializableObjectWithMetadata> rhs(
+            new otio::SerializableObjectWithMetadata("", rhs_wrapped));
+    return lhs->is_equivalent_to(*rhs.value);
+}
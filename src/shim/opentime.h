@@ -0,0 +1,56 @@
+#pragma once
+
+// Shared helpers for the opentime part of the shim. These sources only work
+// on the C structs, so build.rs compiles them even when OpenTimelineIO is not
+// installed. An OpenTimeErrorStatus can only be created by the native
+// library, so without it (SHIM_OTIO_NATIVE unset) every caller passes NULL.
+
+#include <cmath>
+#include <string>
+
+#include "copentime/errorStatus.h"
+#include "copentime/rationalTime.h"
+
+#ifdef SHIM_OTIO_NATIVE
+# include <opentime/errorStatus.h>
+#endif
+
+inline void shim_set_opentime_error(
+        OpenTimeErrorStatus *error_status,
+        OpenTime_ErrorStatus_Outcome outcome,
+        std::string const &details) {
+#ifdef SHIM_OTIO_NATIVE
+    namespace opentime_ = opentime::OPENTIME_VERSION;
+    if (error_status) {
+        *reinterpret_cast<opentime_::ErrorStatus *>(error_status) = opentime_::ErrorStatus(
+                static_cast<opentime_::ErrorStatus::Outcome>(outcome), details);
+    }
+#else
+    (void) error_status;
+    (void) outcome;
+    (void) details;
+#endif
+}
+
+inline double shim_round(double value, OpenTime_RoundingPolicy rounding_policy) {
+    switch (rounding_policy) {
+        case OpenTime_RoundingPolicy_Floor:
+            return std::floor(value);
+        case OpenTime_RoundingPolicy_Ceil:
+            return std::ceil(value);
+        case OpenTime_RoundingPolicy_NearestEven:
+            return std::nearbyint(value);
+        default:
+            return std::round(value);
+    }
+}
+
+// Rounds a frame count, treating values within 1e-9 of a whole frame as
+// whole so that rescaling noise (25 to 24 and back) does not move them.
+inline double shim_round_frames(double value, OpenTime_RoundingPolicy rounding_policy) {
+    double nearest = std::round(value);
+    if (std::abs(value - nearest) < 1e-9) {
+        return nearest;
+    }
+    return shim_round(value, rounding_policy);
+}
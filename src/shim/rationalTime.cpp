@@ -0,0 +1,181 @@
+// Frame rounding declared in rationalTime.h.
+
+#include "export.h"
+#include "opentime.h"
+
+#include "copentime/rationalTime.h"
+
+#include <cstdint>
+#include <cstdio>
+#include <cstdlib>
+#include <cstring>
+#include <limits>
+#include <string>
+
+namespace {
+
+// Saturates rather than overflowing; NaN gives 0.
+int64_t to_int64(double value) {
+    if (std::isnan(value)) {
+        return 0;
+    }
+    if (value >= 9223372036854775807.0) {
+        return std::numeric_limits<int64_t>::max();
+    }
+    if (value <= -9223372036854775808.0) {
+        return std::numeric_limits<int64_t>::min();
+    }
+    return int64_t(value);
+}
+
+} // namespace
+
+int64_t RationalTime_to_frames_rounded(RationalTime self, OpenTime_RoundingPolicy rounding_policy) {
+    return to_int64(shim_round_frames(self.value, rounding_policy));
+}
+
+int64_t RationalTime_to_frames_rounded_with_rate(
+        RationalTime self, double rate, OpenTime_RoundingPolicy rounding_policy) {
+    return to_int64(shim_round_frames(self.value * rate / self.rate, rounding_policy));
+}
+
+RationalTime RationalTime_rescaled_to_rounded(
+        RationalTime self, double new_rate, OpenTime_RoundingPolicy rounding_policy) {
+    return RationalTime{
+            shim_round_frames(self.value * new_rate / self.rate, rounding_policy), new_rate};
+}
+
+RationalTime RationalTime_snap_to_frame(RationalTime self, OpenTime_RoundingPolicy rounding_policy) {
+    return RationalTime{shim_round_frames(self.value, rounding_policy), self.rate};
+}
+
+namespace {
+
+int frames_per_foot(OpenTime_FilmGauge gauge) {
+    switch (gauge) {
+        case OpenTime_FilmGauge_35mm_4Perf:
+            return 16;
+        case OpenTime_FilmGauge_35mm_2Perf:
+            return 32;
+        case OpenTime_FilmGauge_16mm:
+            return 40;
+        default:
+            return 0;
+    }
+}
+
+} // namespace
+
+RationalTime RationalTime_from_feet_frames(
+        const char *feet_frames,
+        double rate,
+        OpenTime_FilmGauge gauge,
+        OpenTimeErrorStatus *error_status) {
+    RationalTime invalid{0, 0};
+    int per_foot = frames_per_foot(gauge);
+    if (per_foot == 0) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIME_STRING, "unknown film gauge");
+        return invalid;
+    }
+    if (!(rate > 0)) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "rate is not positive");
+        return invalid;
+    }
+    char const *cursor = feet_frames;
+    bool negative = *cursor == '-';
+    if (negative) {
+        cursor++;
+    }
+    char *end = nullptr;
+    long long feet = -1, frames = -1;
+    if (*cursor >= '0' && *cursor <= '9') {
+        feet = std::strtoll(cursor, &end, 10);
+        cursor = end;
+    }
+    if (feet >= 0 && *cursor == '+' && cursor[1] >= '0' && cursor[1] <= '9') {
+        frames = std::strtoll(cursor + 1, &end, 10);
+        cursor = end;
+    }
+    if (frames < 0 || frames >= per_foot || *cursor != '\0') {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_INVALID_TIME_STRING,
+                std::string("cannot parse feet+frames ") + feet_frames);
+        return invalid;
+    }
+    double value = double(feet) * per_foot + double(frames);
+    return RationalTime{negative ? -value : value, rate};
+}
+
+RationalTime RationalTime_from_samples(int64_t samples, double sample_rate) {
+    return RationalTime{double(samples), sample_rate};
+}
+
+const char *RationalTime_to_feet_frames(RationalTime self, OpenTime_FilmGauge gauge) {
+    int per_foot = frames_per_foot(gauge);
+    if (per_foot == 0) {
+        return copy_string("");
+    }
+    int64_t frames = to_int64(shim_round_frames(self.value, OpenTime_RoundingPolicy_Nearest));
+    bool negative = frames < 0;
+    uint64_t count = negative ? uint64_t(0) - uint64_t(frames) : uint64_t(frames);
+    char buffer[48];
+    std::snprintf(
+            buffer,
+            sizeof(buffer),
+            "%s%llu+%02llu",
+            negative ? "-" : "",
+            (unsigned long long) (count / per_foot),
+            (unsigned long long) (count % per_foot));
+    return copy_string(buffer);
+}
+
+int64_t RationalTime_to_samples(
+        RationalTime self, double sample_rate, OpenTime_RoundingPolicy rounding_policy) {
+    return RationalTime_to_frames_rounded_with_rate(self, sample_rate, rounding_policy);
+}
+
+namespace {
+
+double seconds(RationalTime time) { return time.value / time.rate; }
+
+// RationalTime_is_invalid_time, plus infinite values at infinite rates,
+// whose seconds are NaN too.
+bool is_invalid(RationalTime time) {
+    return std::isnan(time.rate) || std::isnan(time.value) || time.rate <= 0 ||
+           std::isnan(seconds(time));
+}
+
+} // namespace
+
+int RationalTime_cmp(RationalTime lhs, RationalTime rhs) {
+    double left = seconds(lhs);
+    double right = seconds(rhs);
+    return left < right ? -1 : (right < left ? 1 : 0);
+}
+
+int RationalTime_total_order(RationalTime lhs, RationalTime rhs) {
+    bool lhs_invalid = is_invalid(lhs);
+    bool rhs_invalid = is_invalid(rhs);
+    if (lhs_invalid || rhs_invalid) {
+        return int(rhs_invalid) - int(lhs_invalid);
+    }
+    return RationalTime_cmp(lhs, rhs);
+}
+
+uint64_t RationalTime_hash(RationalTime self) {
+    if (is_invalid(self)) {
+        return 0;
+    }
+    // Adding 0 turns -0 into +0, which compares equal to it.
+    double value = seconds(self) + 0.0;
+    uint64_t bits;
+    std::memcpy(&bits, &value, sizeof(bits));
+    // splitmix64's finaliser, so that nearby times spread out.
+    bits += 0x9e3779b97f4a7c15;
+    bits = (bits ^ (bits >> 30)) * 0xbf58476d1ce4e5b9;
+    bits = (bits ^ (bits >> 27)) * 0x94d049bb133111eb;
+    return bits ^ (bits >> 31);
+}
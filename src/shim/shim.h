@@ -0,0 +1,64 @@
+#pragma once
+
+// Shared helpers for the C++ shim. The C bindings hand out OpenTimelineIO
+// objects as opaque pointers, so the shim converts between the two with
+// reinterpret_cast exactly as the upstream bindings do.
+
+#include <opentimelineio/errorStatus.h>
+#include <opentimelineio/version.h>
+
+#include <string>
+
+#include "copentime/optionalOpenTime.h"
+#include "copentime/rationalTime.h"
+#include "copentime/timeRange.h"
+#include "copentimelineio/errorStatus.h"
+
+namespace otio = opentimelineio::OPENTIMELINEIO_VERSION;
+
+template <typename To, typename From>
+inline To *shim_cast(From *from) {
+    return reinterpret_cast<To *>(from);
+}
+
+inline void shim_set_error(
+        OTIOErrorStatus *error_status,
+        otio::ErrorStatus::Outcome outcome,
+        std::string const &details) {
+    if (error_status) {
+        *shim_cast<otio::ErrorStatus>(error_status) =
+                otio::ErrorStatus(outcome, details);
+    }
+}
+
+inline RationalTime shim_time(otio::RationalTime const &time) {
+    return RationalTime{time.value(), time.rate()};
+}
+
+inline otio::RationalTime shim_time(RationalTime const &time) {
+    return otio::RationalTime(time.value, time.rate);
+}
+
+inline TimeRange shim_range(otio::TimeRange const &range) {
+    return TimeRange{shim_time(range.start_time()), shim_time(range.duration())};
+}
+
+inline otio::TimeRange shim_range(TimeRange const &range) {
+    return otio::TimeRange(shim_time(range.start_time), shim_time(range.duration));
+}
+
+inline OptionalRationalTime shim_optional_time(otio::optional<otio::RationalTime> const &time) {
+    OptionalRationalTime result{};
+    if (time) {
+        result.value = shim_time(*time);
+        result.valid = true;
+    }
+    return result;
+}
+
+inline otio::optional<otio::TimeRange> shim_optional_range(OptionalTimeRange const &range) {
+    if (!range.valid) {
+        return {};
+    }
+    return shim_range(range.value);
+}
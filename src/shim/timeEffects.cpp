@@ -0,0 +1,130 @@
+// Time-effect-aware mapping between a clip's parent and its media.
+
+#include "shim.h"
+
+#include <opentimelineio/clip.h>
+#include <opentimelineio/composition.h>
+#include <opentimelineio/freezeFrame.h>
+#include <opentimelineio/linearTimeWarp.h>
+
+#include "copentimelineio/clip.h"
+#include "copentimelineio/freezeFrame.h"
+#include "copentimelineio/linearTimeWarp.h"
+
+#include <cmath>
+
+namespace {
+
+struct ClipMapping {
+    // Where the clip sits in its parent, or at zero when it has none.
+    otio::TimeRange range_in_parent;
+    otio::TimeRange trimmed_range;
+    double time_scalar = 1;
+
+    double rate() const { return trimmed_range.start_time().rate(); }
+
+    double duration() const {
+        return trimmed_range.duration().rescaled_to(rate()).value();
+    }
+
+    // In frames of rate(); a reversed clip starts on the last of these.
+    double consumed_duration() const {
+        return time_scalar == 0 ? 1 : duration() * std::fabs(time_scalar);
+    }
+
+    otio::RationalTime media_time_for_offset(otio::RationalTime offset) const {
+        double frames = offset.rescaled_to(rate()).value();
+        double media = time_scalar < 0 ? consumed_duration() - 1 + frames * time_scalar
+                                       : frames * time_scalar;
+        return trimmed_range.start_time() + otio::RationalTime(media, rate());
+    }
+
+    otio::TimeRange consumed_media_range() const {
+        return otio::TimeRange(
+                trimmed_range.start_time(), otio::RationalTime(consumed_duration(), rate()));
+    }
+};
+
+bool clip_mapping(otio::Clip *clip, ClipMapping &mapping, otio::ErrorStatus *error_status) {
+    mapping.trimmed_range = clip->trimmed_range(error_status);
+    if (otio::is_error(error_status)) {
+        return false;
+    }
+    mapping.range_in_parent = otio::TimeRange(
+            otio::RationalTime(0, mapping.rate()), mapping.trimmed_range.duration());
+    if (auto parent = clip->parent()) {
+        mapping.range_in_parent = parent->range_of_child(clip, error_status);
+        if (otio::is_error(error_status)) {
+            return false;
+        }
+    }
+    for (auto const &effect : clip->effects()) {
+        if (auto warp = dynamic_cast<otio::LinearTimeWarp *>(effect.value)) {
+            mapping.time_scalar *= warp->time_scalar();
+        }
+    }
+    return true;
+}
+
+} // namespace
+
+RationalTime
+LinearTimeWarp_apply_to_time(LinearTimeWarp *self, RationalTime time_from_start) {
+    double time_scalar = shim_cast<otio::LinearTimeWarp>(self)->time_scalar();
+    return RationalTime{time_from_start.value * time_scalar, time_from_start.rate};
+}
+
+OptionalRationalTime LinearTimeWarp_apply_inverse_to_time(
+        LinearTimeWarp *self, RationalTime media_time_from_start) {
+    double time_scalar = shim_cast<otio::LinearTimeWarp>(self)->time_scalar();
+    if (time_scalar == 0) {
+        return shim_optional_time({});
+    }
+    return shim_optional_time(otio::RationalTime(
+            media_time_from_start.value / time_scalar, media_time_from_start.rate));
+}
+
+RationalTime FreezeFrame_apply_to_time(FreezeFrame *, RationalTime time_from_start) {
+    return RationalTime{0, time_from_start.rate};
+}
+
+RationalTime Clip_media_time_for_parent_time(
+        Clip *self, RationalTime parent_time, OTIOErrorStatus *error_status) {
+    ClipMapping mapping;
+    if (!clip_mapping(
+                shim_cast<otio::Clip>(self), mapping, shim_cast<otio::ErrorStatus>(error_status))) {
+        return RationalTime{0, parent_time.rate};
+    }
+    auto offset = shim_time(parent_time) - mapping.range_in_parent.start_time();
+    return shim_time(mapping.media_time_for_offset(offset));
+}
+
+OptionalRationalTime Clip_parent_time_for_media_time(
+        Clip *self, RationalTime media_time, OTIOErrorStatus *error_status) {
+    ClipMapping mapping;
+    if (!clip_mapping(
+                shim_cast<otio::Clip>(self), mapping, shim_cast<otio::ErrorStatus>(error_status)) ||
+        mapping.time_scalar == 0) {
+        return shim_optional_time({});
+    }
+    double media = (shim_time(media_time) - mapping.trimmed_range.start_time())
+                           .rescaled_to(mapping.rate())
+                           .value();
+    double frames = mapping.time_scalar < 0
+                            ? (mapping.consumed_duration() - 1 - media) / -mapping.time_scalar
+                            : media / mapping.time_scalar;
+    if (frames < 0 || frames >= mapping.duration()) {
+        return shim_optional_time({});
+    }
+    return shim_optional_time(
+            mapping.range_in_parent.start_time() + otio::RationalTime(frames, mapping.rate()));
+}
+
+TimeRange Clip_consumed_media_range(Clip *self, OTIOErrorStatus *error_status) {
+    ClipMapping mapping;
+    if (!clip_mapping(
+                shim_cast<otio::Clip>(self), mapping, shim_cast<otio::ErrorStatus>(error_status))) {
+        return TimeRange{};
+    }
+    return shim_range(mapping.consumed_media_range());
+}
@@ -0,0 +1,99 @@
+// Frame snapping and iteration declared in timeRange.h.
+
+#include "opentime.h"
+
+#include "copentime/timeRange.h"
+
+#include <cmath>
+#include <cstdint>
+
+TimeRange TimeRange_snap_to_frame(
+        TimeRange self,
+        OpenTime_RoundingPolicy start_rounding_policy,
+        OpenTime_RoundingPolicy end_rounding_policy) {
+    double rate = self.start_time.rate;
+    double end = self.start_time.value + self.duration.value * rate / self.duration.rate;
+    double snapped_start = shim_round_frames(self.start_time.value, start_rounding_policy);
+    double snapped_end = shim_round_frames(end, end_rounding_policy);
+    return TimeRange{
+            RationalTime{snapped_start, rate}, RationalTime{snapped_end - snapped_start, rate}};
+}
+
+struct TimeRangeFrameIterator {
+    double rate;
+    int64_t first;
+    int64_t step;
+    // Indices of the frames still to come, first + index * step; front is
+    // taken by next unless reverse is set.
+    int64_t front;
+    int64_t back;
+    bool reverse;
+};
+
+TimeRangeFrameIterator *TimeRange_frames(
+        TimeRange self, double rate, bool reverse, OpenTimeErrorStatus *error_status) {
+    return TimeRange_frames_step_by(self, rate, 1, reverse, error_status);
+}
+
+TimeRangeFrameIterator *TimeRange_frames_step_by(
+        TimeRange self,
+        double rate,
+        int64_t step,
+        bool reverse,
+        OpenTimeErrorStatus *error_status) {
+    if (!std::isfinite(rate) || rate <= 0) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "rate is not positive");
+        return nullptr;
+    }
+    if (step <= 0) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_NEGATIVE_VALUE, "step is not positive");
+        return nullptr;
+    }
+    double start = self.start_time.value * rate / self.start_time.rate;
+    double end = start + self.duration.value * rate / self.duration.rate;
+    double first = shim_round_frames(start, OpenTime_RoundingPolicy_Ceil);
+    double last = shim_round_frames(end, OpenTime_RoundingPolicy_Ceil);
+    int64_t count = 0;
+    if (std::isfinite(first) && std::isfinite(last) && first < last) {
+        count = (int64_t(last - first) + step - 1) / step;
+    }
+    return new TimeRangeFrameIterator{rate, int64_t(first), step, 0, count, reverse};
+}
+
+namespace {
+
+bool take_front(TimeRangeFrameIterator *iter, RationalTime *frame_time) {
+    if (iter->front == iter->back) {
+        return false;
+    }
+    *frame_time = RationalTime{double(iter->first + iter->front * iter->step), iter->rate};
+    iter->front++;
+    return true;
+}
+
+bool take_back(TimeRangeFrameIterator *iter, RationalTime *frame_time) {
+    if (iter->front == iter->back) {
+        return false;
+    }
+    iter->back--;
+    *frame_time = RationalTime{double(iter->first + iter->back * iter->step), iter->rate};
+    return true;
+}
+
+} // namespace
+
+bool TimeRangeFrameIterator_next(TimeRangeFrameIterator *iter, RationalTime *frame_time) {
+    return iter->reverse ? take_back(iter, frame_time) : take_front(iter, frame_time);
+}
+
+bool TimeRangeFrameIterator_next_back(TimeRangeFrameIterator *iter, RationalTime *frame_time) {
+    return iter->reverse ? take_front(iter, frame_time) : take_back(iter, frame_time);
+}
+
+int64_t TimeRangeFrameIterator_remaining(TimeRangeFrameIterator *iter) {
+    return iter->back - iter->front;
+}
+
+void TimeRangeFrameIterator_destroy(TimeRangeFrameIterator *self) { delete self; }
@@ -0,0 +1,260 @@
+// Disjoint range sets declared in timeRangeSet.h.
+
+#include "opentime.h"
+
+#include "copentime/timeRangeSet.h"
+
+#include <algorithm>
+#include <cmath>
+#include <utility>
+#include <vector>
+
+struct TimeRangeSet {
+    // Zero until the first range is inserted; every interval is at this rate.
+    double rate = 0;
+    // Sorted, disjoint and non-adjacent [start, end) values at rate.
+    std::vector<std::pair<double, double>> intervals;
+};
+
+namespace {
+
+using Interval = std::pair<double, double>;
+
+bool is_valid_rate(double rate) { return std::isfinite(rate) && rate > 0; }
+
+// Fails, so that the range is ignored, if either of its rates is not
+// positive.
+bool interval_at(TimeRange range, double rate, Interval *result) {
+    if (!is_valid_rate(range.start_time.rate) || !is_valid_rate(range.duration.rate)) {
+        return false;
+    }
+    double start = range.start_time.value * rate / range.start_time.rate;
+    double duration = range.duration.value * rate / range.duration.rate;
+    *result = {start, start + duration};
+    return true;
+}
+
+double rate_for(TimeRange range) { return range.start_time.rate; }
+
+// The first interval ending after value, the only one that can hold it.
+std::vector<Interval>::const_iterator first_ending_after(
+        std::vector<Interval> const &intervals, double value) {
+    return std::partition_point(intervals.begin(), intervals.end(), [&](Interval const &interval) {
+        return interval.second <= value;
+    });
+}
+
+// The set's intervals at another rate.
+std::vector<Interval> intervals_at(TimeRangeSet const &set, double rate) {
+    if (set.rate == rate || set.rate == 0) {
+        return set.intervals;
+    }
+    std::vector<Interval> result;
+    for (auto const &interval : set.intervals) {
+        result.emplace_back(interval.first * rate / set.rate, interval.second * rate / set.rate);
+    }
+    return result;
+}
+
+// Merges added with the intervals it overlaps or touches, in place.
+void add(std::vector<Interval> &intervals, Interval added) {
+    if (!(added.first < added.second)) {
+        return;
+    }
+    auto first = std::partition_point(
+            intervals.begin(), intervals.end(), [&](Interval const &interval) {
+                return interval.second < added.first;
+            });
+    auto last = std::partition_point(first, intervals.end(), [&](Interval const &interval) {
+        return interval.first <= added.second;
+    });
+    if (first == last) {
+        intervals.insert(first, added);
+        return;
+    }
+    first->first = std::min(added.first, first->first);
+    first->second = std::max(added.second, (last - 1)->second);
+    intervals.erase(first + 1, last);
+}
+
+// Cuts removed out of the intervals it overlaps, in place.
+void subtract(std::vector<Interval> &intervals, Interval removed) {
+    if (!(removed.first < removed.second)) {
+        return;
+    }
+    auto first = std::partition_point(
+            intervals.begin(), intervals.end(), [&](Interval const &interval) {
+                return interval.second <= removed.first;
+            });
+    auto last = std::partition_point(first, intervals.end(), [&](Interval const &interval) {
+        return interval.first < removed.second;
+    });
+    if (first == last) {
+        return;
+    }
+    std::vector<Interval> kept;
+    if (first->first < removed.first) {
+        kept.emplace_back(first->first, removed.first);
+    }
+    if (removed.second < (last - 1)->second) {
+        kept.emplace_back(removed.second, (last - 1)->second);
+    }
+    auto position = intervals.erase(first, last);
+    intervals.insert(position, kept.begin(), kept.end());
+}
+
+TimeRange range_of(Interval interval, double rate) {
+    return TimeRange{
+            RationalTime{interval.first, rate},
+            RationalTime{interval.second - interval.first, rate}};
+}
+
+// The rate a result of lhs and rhs is kept at.
+double result_rate(TimeRangeSet const &lhs, TimeRangeSet const &rhs) {
+    return lhs.rate != 0 ? lhs.rate : rhs.rate;
+}
+
+} // namespace
+
+TimeRangeSet *TimeRangeSet_create() { return new TimeRangeSet; }
+
+void TimeRangeSet_destroy(TimeRangeSet *self) { delete self; }
+
+TimeRangeSet *TimeRangeSet_clone(TimeRangeSet *self) { return new TimeRangeSet(*self); }
+
+void TimeRangeSet_insert(TimeRangeSet *self, TimeRange time_range) {
+    double rate = self->rate != 0 ? self->rate : rate_for(time_range);
+    Interval interval;
+    if (!interval_at(time_range, rate, &interval)) {
+        return;
+    }
+    self->rate = rate;
+    add(self->intervals, interval);
+}
+
+void TimeRangeSet_remove(TimeRangeSet *self, TimeRange time_range) {
+    Interval interval;
+    if (self->rate == 0 || !interval_at(time_range, self->rate, &interval)) {
+        return;
+    }
+    subtract(self->intervals, interval);
+}
+
+void TimeRangeSet_clear(TimeRangeSet *self) { *self = TimeRangeSet(); }
+
+int TimeRangeSet_size(TimeRangeSet *self) { return int(self->intervals.size()); }
+
+bool TimeRangeSet_empty(TimeRangeSet *self) { return self->intervals.empty(); }
+
+TimeRange TimeRangeSet_at(TimeRangeSet *self, int pos) {
+    return range_of(self->intervals.at(size_t(pos)), self->rate);
+}
+
+OptionalTimeRange TimeRangeSet_bounds(TimeRangeSet *self) {
+    OptionalTimeRange result{};
+    if (!self->intervals.empty()) {
+        result.value = range_of(
+                {self->intervals.front().first, self->intervals.back().second}, self->rate);
+        result.valid = true;
+    }
+    return result;
+}
+
+RationalTime TimeRangeSet_coverage(TimeRangeSet *self) {
+    double total = 0;
+    for (auto const &interval : self->intervals) {
+        total += interval.second - interval.first;
+    }
+    return RationalTime{total, self->rate != 0 ? self->rate : 1};
+}
+
+bool TimeRangeSet_contains_rational_time(TimeRangeSet *self, RationalTime other) {
+    if (self->rate == 0 || !is_valid_rate(other.rate)) {
+        return false;
+    }
+    double value = other.value * self->rate / other.rate;
+    auto interval = first_ending_after(self->intervals, value);
+    return interval != self->intervals.end() && interval->first <= value;
+}
+
+bool TimeRangeSet_contains_time_range(TimeRangeSet *self, TimeRange other) {
+    Interval range;
+    if (self->rate == 0 || !interval_at(other, self->rate, &range)) {
+        return false;
+    }
+    // The last interval starting at or before the range is the only one
+    // that can hold it.
+    auto after = std::partition_point(
+            self->intervals.begin(), self->intervals.end(), [&](Interval const &interval) {
+                return interval.first <= range.first;
+            });
+    return after != self->intervals.begin() && range.second <= (after - 1)->second;
+}
+
+bool TimeRangeSet_overlaps_time_range(TimeRangeSet *self, TimeRange other) {
+    Interval range;
+    if (self->rate == 0 || !interval_at(other, self->rate, &range)) {
+        return false;
+    }
+    auto interval = first_ending_after(self->intervals, range.first);
+    return interval != self->intervals.end() && interval->first < range.second;
+}
+
+TimeRangeSet *TimeRangeSet_union(TimeRangeSet *lhs, TimeRangeSet *rhs) {
+    auto result = new TimeRangeSet;
+    result->rate = result_rate(*lhs, *rhs);
+    result->intervals = intervals_at(*lhs, result->rate);
+    for (auto const &interval : intervals_at(*rhs, result->rate)) {
+        add(result->intervals, interval);
+    }
+    return result;
+}
+
+TimeRangeSet *TimeRangeSet_intersection(TimeRangeSet *lhs, TimeRangeSet *rhs) {
+    auto result = new TimeRangeSet;
+    result->rate = result_rate(*lhs, *rhs);
+    auto left = intervals_at(*lhs, result->rate);
+    auto right = intervals_at(*rhs, result->rate);
+    size_t i = 0, j = 0;
+    while (i < left.size() && j < right.size()) {
+        double start = std::max(left[i].first, right[j].first);
+        double end = std::min(left[i].second, right[j].second);
+        if (start < end) {
+            result->intervals.emplace_back(start, end);
+        }
+        if (left[i].second < right[j].second) {
+            i++;
+        } else {
+            j++;
+        }
+    }
+    return result;
+}
+
+TimeRangeSet *TimeRangeSet_difference(TimeRangeSet *lhs, TimeRangeSet *rhs) {
+    auto result = new TimeRangeSet;
+    result->rate = result_rate(*lhs, *rhs);
+    result->intervals = intervals_at(*lhs, result->rate);
+    for (auto const &interval : intervals_at(*rhs, result->rate)) {
+        subtract(result->intervals, interval);
+    }
+    return result;
+}
+
+TimeRangeSet *TimeRangeSet_complement_within(TimeRangeSet *self, TimeRange bounds) {
+    auto result = new TimeRangeSet;
+    Interval within;
+    if (!interval_at(bounds, rate_for(bounds), &within)) {
+        return result;
+    }
+    result->rate = rate_for(bounds);
+    add(result->intervals, within);
+    for (auto const &interval : intervals_at(*self, result->rate)) {
+        subtract(result->intervals, interval);
+    }
+    return result;
+}
+
+bool TimeRangeSet_equal(TimeRangeSet *lhs, TimeRangeSet *rhs) {
+    return lhs->intervals == intervals_at(*rhs, result_rate(*lhs, *rhs));
+}
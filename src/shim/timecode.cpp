@@ -0,0 +1,350 @@
+// Structured timecode declared in timecode.h.
+
+#include "opentime.h"
+#include "export.h"
+
+#include "copentime/timecode.h"
+
+#include <cmath>
+#include <cstdint>
+#include <cstdio>
+#include <cstdlib>
+#include <cstring>
+#include <limits>
+#include <string>
+
+namespace {
+
+int64_t const seconds_per_day = 24 * 60 * 60;
+
+Timecode const invalid_timecode{};
+
+bool is_valid_rate(double rate) { return std::isfinite(rate) && rate > 0; }
+
+// The frames counted per second, e.g. 30 for 29.97.
+int64_t nominal_rate(double rate) { return int64_t(std::round(rate)); }
+
+bool allows_drop_frame(double rate) {
+    int64_t nominal = nominal_rate(rate);
+    return nominal % 30 == 0 && std::fabs(rate - double(nominal) * 1000 / 1001) < 0.001;
+}
+
+// Frame numbers skipped at the start of each minute but every tenth.
+int64_t dropped_per_minute(double rate) { return nominal_rate(rate) / 15; }
+
+bool resolve_drop_frame(
+        double rate,
+        OpenTime_IsDropFrameRate drop_frame,
+        bool *result,
+        OpenTimeErrorStatus *error_status) {
+    if (!is_valid_rate(rate)) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "rate is not positive");
+        return false;
+    }
+    switch (drop_frame) {
+        case OpenTime_IsDropFrameRate_ForceYes:
+            if (!allows_drop_frame(rate)) {
+                shim_set_opentime_error(
+                        error_status,
+                        OpenTime_ErrorStatus_Outcome_INVALID_RATE_FOR_DROP_FRAME_TIMECODE,
+                        "rate " + std::to_string(rate) + " has no drop-frame timecode");
+                return false;
+            }
+            *result = true;
+            return true;
+        case OpenTime_IsDropFrameRate_ForceNo:
+            *result = false;
+            return true;
+        default:
+            *result = allows_drop_frame(rate);
+            return true;
+    }
+}
+
+// Builds the timecode for a frame count whose drop frame is already resolved,
+// failing with OVERFLOW if its hours do not fit in an int.
+Timecode from_frames(
+        int64_t frames, double rate, bool drop_frame, OpenTimeErrorStatus *error_status) {
+    Timecode result{};
+    result.rate = rate;
+    result.drop_frame = drop_frame;
+    result.negative = frames < 0;
+    // Counted as unsigned so that INT64_MIN has a magnitude.
+    uint64_t count = frames < 0 ? uint64_t(0) - uint64_t(frames) : uint64_t(frames);
+    uint64_t nominal = uint64_t(nominal_rate(rate));
+    if (drop_frame) {
+        uint64_t dropped = uint64_t(dropped_per_minute(rate));
+        uint64_t per_minute = nominal * 60 - dropped;
+        uint64_t per_ten_minutes = nominal * 600 - dropped * 9;
+        uint64_t tens = count / per_ten_minutes;
+        uint64_t rest = count % per_ten_minutes;
+        count += dropped * 9 * tens;
+        if (rest > dropped) {
+            count += dropped * ((rest - dropped) / per_minute);
+        }
+    }
+    result.frames = int(count % nominal);
+    count /= nominal;
+    result.seconds = int(count % 60);
+    count /= 60;
+    result.minutes = int(count % 60);
+    count /= 60;
+    if (count > uint64_t(std::numeric_limits<int>::max())) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_OVERFLOW, "hours do not fit in an int");
+        return invalid_timecode;
+    }
+    result.hours = int(count);
+    return result;
+}
+
+// Adds two frame counts, failing with OVERFLOW if the sum does not fit.
+bool add_frames(int64_t lhs, int64_t rhs, int64_t *result, OpenTimeErrorStatus *error_status) {
+    if (__builtin_add_overflow(lhs, rhs, result)) {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_OVERFLOW,
+                "frame count does not fit in int64");
+        return false;
+    }
+    return true;
+}
+
+bool valid_fields(Timecode const &timecode, OpenTimeErrorStatus *error_status) {
+    int64_t nominal = nominal_rate(timecode.rate);
+    bool valid = timecode.hours >= 0 && timecode.minutes >= 0 && timecode.minutes < 60 &&
+                 timecode.seconds >= 0 && timecode.seconds < 60 && timecode.frames >= 0 &&
+                 timecode.frames < nominal;
+    if (valid && timecode.drop_frame && timecode.seconds == 0 && timecode.minutes % 10 != 0 &&
+        timecode.frames < dropped_per_minute(timecode.rate)) {
+        valid = false;
+    }
+    if (!valid) {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_STRING,
+                "timecode fields are out of range");
+    }
+    return valid;
+}
+
+bool check_timecode(Timecode const &timecode, OpenTimeErrorStatus *error_status) {
+    if (!is_valid_rate(timecode.rate)) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "rate is not positive");
+        return false;
+    }
+    return true;
+}
+
+bool parse_field(char const *&cursor, int *value) {
+    if (*cursor < '0' || *cursor > '9') {
+        return false;
+    }
+    char *end = nullptr;
+    long parsed = std::strtol(cursor, &end, 10);
+    if (parsed > 1000000) {
+        return false;
+    }
+    *value = int(parsed);
+    cursor = end;
+    return true;
+}
+
+} // namespace
+
+Timecode Timecode_create(
+        int hours,
+        int minutes,
+        int seconds,
+        int frames,
+        double rate,
+        OpenTime_IsDropFrameRate drop_frame,
+        OpenTimeErrorStatus *error_status) {
+    Timecode result{};
+    if (!resolve_drop_frame(rate, drop_frame, &result.drop_frame, error_status)) {
+        return invalid_timecode;
+    }
+    result.hours = hours;
+    result.minutes = minutes;
+    result.seconds = seconds;
+    result.frames = frames;
+    result.rate = rate;
+    if (!valid_fields(result, error_status)) {
+        return invalid_timecode;
+    }
+    return result;
+}
+
+Timecode
+Timecode_from_string(const char *timecode, double rate, OpenTimeErrorStatus *error_status) {
+    if (!is_valid_rate(rate)) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "rate is not positive");
+        return invalid_timecode;
+    }
+    Timecode result{};
+    result.rate = rate;
+    char const *cursor = timecode;
+    if (*cursor == '-') {
+        result.negative = true;
+        cursor++;
+    }
+    int *fields[] = {&result.hours, &result.minutes, &result.seconds, &result.frames};
+    bool parsed = true;
+    for (int index = 0; parsed && index < 4; index++) {
+        if (index > 0) {
+            char separator = *cursor++;
+            if (index == 3 && separator == ';') {
+                result.drop_frame = true;
+            } else if (separator != ':') {
+                parsed = false;
+                break;
+            }
+        }
+        parsed = parse_field(cursor, fields[index]);
+    }
+    if (!parsed || *cursor != '\0') {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_STRING,
+                std::string("cannot parse timecode ") + timecode);
+        return invalid_timecode;
+    }
+    if (result.drop_frame && !allows_drop_frame(rate)) {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_INVALID_RATE_FOR_DROP_FRAME_TIMECODE,
+                "rate " + std::to_string(rate) + " has no drop-frame timecode");
+        return invalid_timecode;
+    }
+    if (!valid_fields(result, error_status)) {
+        return invalid_timecode;
+    }
+    return result;
+}
+
+const char *Timecode_to_string(Timecode self) {
+    char buffer[64];
+    std::snprintf(
+            buffer,
+            sizeof(buffer),
+            "%s%02d:%02d:%02d%c%02d",
+            self.negative ? "-" : "",
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.drop_frame ? ';' : ':',
+            self.frames);
+    return copy_string(buffer);
+}
+
+Timecode Timecode_from_frames(
+        int64_t frames,
+        double rate,
+        OpenTime_IsDropFrameRate drop_frame,
+        OpenTimeErrorStatus *error_status) {
+    bool resolved = false;
+    if (!resolve_drop_frame(rate, drop_frame, &resolved, error_status)) {
+        return invalid_timecode;
+    }
+    return from_frames(frames, rate, resolved, error_status);
+}
+
+int64_t Timecode_to_frames(Timecode self) {
+    if (!is_valid_rate(self.rate)) {
+        return 0;
+    }
+    int64_t nominal = nominal_rate(self.rate);
+    int64_t minutes = int64_t(self.hours) * 60 + self.minutes;
+    int64_t frames = (minutes * 60 + self.seconds) * nominal + self.frames;
+    if (self.drop_frame) {
+        frames -= dropped_per_minute(self.rate) * (minutes - minutes / 10);
+    }
+    return self.negative ? -frames : frames;
+}
+
+Timecode Timecode_from_rational_time(
+        RationalTime rational_time,
+        double rate,
+        OpenTime_IsDropFrameRate drop_frame,
+        OpenTimeErrorStatus *error_status) {
+    double frames = shim_round_frames(
+            rational_time.value * rate / rational_time.rate, OpenTime_RoundingPolicy_Nearest);
+    if (!std::isfinite(frames)) {
+        shim_set_opentime_error(
+                error_status, OpenTime_ErrorStatus_Outcome_INVALID_TIMECODE_RATE, "time is invalid");
+        return invalid_timecode;
+    }
+    return Timecode_from_frames(int64_t(frames), rate, drop_frame, error_status);
+}
+
+RationalTime Timecode_to_rational_time(Timecode self) {
+    return RationalTime{double(Timecode_to_frames(self)), self.rate};
+}
+
+Timecode Timecode_add_frames(Timecode self, int64_t frames, OpenTimeErrorStatus *error_status) {
+    if (!check_timecode(self, error_status)) {
+        return invalid_timecode;
+    }
+    int64_t sum = 0;
+    if (!add_frames(Timecode_to_frames(self), frames, &sum, error_status)) {
+        return invalid_timecode;
+    }
+    return from_frames(sum, self.rate, self.drop_frame, error_status);
+}
+
+Timecode Timecode_add(Timecode lhs, Timecode rhs, OpenTimeErrorStatus *error_status) {
+    if (!check_timecode(lhs, error_status) || !check_timecode(rhs, error_status)) {
+        return invalid_timecode;
+    }
+    if (lhs.rate != rhs.rate) {
+        shim_set_opentime_error(
+                error_status,
+                OpenTime_ErrorStatus_Outcome_TIMECODE_RATE_MISMATCH,
+                "timecode rates differ");
+        return invalid_timecode;
+    }
+    int64_t sum = 0;
+    if (!add_frames(Timecode_to_frames(lhs), Timecode_to_frames(rhs), &sum, error_status)) {
+        return invalid_timecode;
+    }
+    return from_frames(sum, lhs.rate, lhs.drop_frame, error_status);
+}
+
+Timecode Timecode_subtract(Timecode lhs, Timecode rhs, OpenTimeErrorStatus *error_status) {
+    rhs.negative = !rhs.negative;
+    return Timecode_add(lhs, rhs, error_status);
+}
+
+Timecode Timecode_wrapped_to_24_hours(Timecode self) {
+    if (!is_valid_rate(self.rate)) {
+        return invalid_timecode;
+    }
+    Timecode day{};
+    day.hours = int(seconds_per_day / 3600);
+    day.rate = self.rate;
+    day.drop_frame = self.drop_frame;
+    int64_t frames_per_day = Timecode_to_frames(day);
+    int64_t frames = Timecode_to_frames(self) % frames_per_day;
+    if (frames < 0) {
+        frames += frames_per_day;
+    }
+    // Less than a day of frames, so the hours always fit.
+    return from_frames(frames, self.rate, self.drop_frame, nullptr);
+}
+
+int Timecode_compare(Timecode lhs, Timecode rhs) {
+    double left = double(Timecode_to_frames(lhs));
+    double right = double(Timecode_to_frames(rhs));
+    if (lhs.rate != rhs.rate) {
+        left *= rhs.rate;
+        right *= lhs.rate;
+    }
+    return left < right ? -1 : (right < left ? 1 : 0);
+}
+
+bool Timecode_equal(Timecode lhs, Timecode rhs) { return Timecode_compare(lhs, rhs) == 0; }
+
+bool Timecode_not_equal(Timecode lhs, Timecode rhs) { return !Timecode_equal(lhs, rhs); }
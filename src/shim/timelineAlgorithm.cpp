@@ -0,0 +1,596 @@
+// Timeline-wide queries declared in timelineAlgorithm.h.
+
+#include "export.h"
+#include "opentime.h"
+#include "shim.h"
+
+#include <opentimelineio/clip.h>
+#include <opentimelineio/externalReference.h>
+#include <opentimelineio/stack.h>
+#include <opentimelineio/timeline.h>
+#include <opentimelineio/track.h>
+#include <opentimelineio/transition.h>
+
+#include "copentimelineio/clip.h"
+#include "copentimelineio/timelineAlgorithm.h"
+
+#include <algorithm>
+#include <cmath>
+#include <map>
+#include <memory>
+#include <sstream>
+#include <string>
+#include <vector>
+
+struct ItemAtTimeVector {
+    std::vector<ItemAtTime> items;
+};
+
+struct MarkerInTimelineVector {
+    std::vector<MarkerInTimeline> markers;
+    std::vector<std::string> owner_paths;
+};
+
+struct PullList {
+    std::vector<PullListEntry> entries;
+    // Owned here; each PullListEntry points into these.
+    std::vector<std::unique_ptr<std::string>> target_urls;
+};
+
+struct SubFrameValueVector {
+    std::vector<SubFrameValue> values;
+    // Owned here; each SubFrameValue points into these.
+    std::vector<std::unique_ptr<std::string>> paths;
+};
+
+namespace {
+
+bool contains(otio::TimeRange const &range, otio::RationalTime const &time) {
+    return !(time < range.start_time()) && time < range.end_time_exclusive();
+}
+
+// Collects the visible items of composition under time, which is in the
+// composition's own coordinates. Stacks are walked top-down.
+bool collect_items_at_time(
+        otio::Composition *composition,
+        otio::RationalTime time,
+        bool skip_disabled,
+        std::vector<ItemAtTime> &items,
+        otio::ErrorStatus *error_status) {
+    auto const &children = composition->children();
+    bool top_down = dynamic_cast<otio::Stack *>(composition) != nullptr;
+    for (size_t n = 0; n < children.size(); n++) {
+        size_t index = top_down ? children.size() - 1 - n : n;
+        auto item = dynamic_cast<otio::Item *>(children[index].value);
+        if (!item || (skip_disabled && !item->enabled())) {
+            continue;
+        }
+        auto range = composition->range_of_child_at_index(int(index), error_status);
+        if (otio::is_error(error_status)) {
+            return false;
+        }
+        if (!contains(range, time)) {
+            continue;
+        }
+        auto trimmed_range = item->trimmed_range(error_status);
+        if (otio::is_error(error_status)) {
+            return false;
+        }
+        auto item_time = time - range.start_time() + trimmed_range.start_time();
+        if (auto nested = dynamic_cast<otio::Composition *>(item)) {
+            if (!collect_items_at_time(nested, item_time, skip_disabled, items, error_status)) {
+                return false;
+            }
+            continue;
+        }
+        if (!item->visible()) {
+            continue;
+        }
+        ItemAtTime found{};
+        found.item = shim_cast<Item>(item);
+        found.parent = shim_cast<Composition>(composition);
+        found.item_time = shim_time(item_time);
+        if (auto clip = dynamic_cast<otio::Clip *>(item)) {
+            auto media_time = Clip_media_time_for_parent_time(
+                    shim_cast<Clip>(clip),
+                    shim_time(time),
+                    shim_cast<OTIOErrorStatus>(error_status));
+            if (otio::is_error(error_status)) {
+                return false;
+            }
+            found.media_time = shim_optional_time(shim_time(media_time));
+        }
+        items.push_back(found);
+    }
+    return true;
+}
+
+// Appends the markers of item and of everything below it, in pre-order.
+bool collect_markers(
+        otio::Item *item,
+        std::string const &parent_path,
+        otio::Stack *stack,
+        otio::RationalTime global_start_time,
+        MarkerInTimelineVector &found,
+        otio::ErrorStatus *error_status) {
+    auto path = parent_path.empty() ? item->name() : parent_path + "/" + item->name();
+    for (auto const &marker : item->markers()) {
+        auto range = marker.value->marked_range();
+        if (item != stack) {
+            range = item->transformed_time_range(range, stack, error_status);
+            if (otio::is_error(error_status)) {
+                return false;
+            }
+        }
+        MarkerInTimeline entry{};
+        entry.marker = shim_cast<Marker>(marker.value);
+        entry.owner = shim_cast<Item>(item);
+        entry.global_range = shim_range(otio::TimeRange(
+                range.start_time() + global_start_time.rescaled_to(range.start_time().rate()),
+                range.duration()));
+        found.markers.push_back(entry);
+        found.owner_paths.push_back(path);
+    }
+    if (auto composition = dynamic_cast<otio::Composition *>(item)) {
+        for (auto const &child : composition->children()) {
+            auto child_item = dynamic_cast<otio::Item *>(child.value);
+            if (child_item &&
+                !collect_markers(
+                        child_item, path, stack, global_start_time, found, error_status)) {
+                return false;
+            }
+        }
+    }
+    return true;
+}
+
+// Rewrites every time value below a timeline at a new rate.
+struct RateConformer {
+    double new_rate;
+    OTIO_RateConform_Mode mode;
+    OpenTime_RoundingPolicy rounding_policy;
+    SubFrameValueVector &sub_frame_values;
+
+    otio::RationalTime conform(
+            otio::RationalTime time,
+            otio::SerializableObject *object,
+            std::string const &path,
+            const char *field) {
+        double value = mode == OTIO_RateConform_Mode_PRESERVE_FRAME_COUNT
+                               ? time.value()
+                               : time.value() * new_rate / time.rate();
+        // Rescaling rates like 25 to 24 leaves floating point noise on
+        // values that are whole frames.
+        double nearest = std::round(value);
+        if (std::abs(value - nearest) < 1e-9) {
+            return otio::RationalTime(nearest, new_rate);
+        }
+        otio::RationalTime conformed(shim_round(value, rounding_policy), new_rate);
+        sub_frame_values.paths.push_back(std::make_unique<std::string>(path));
+        SubFrameValue sub_frame{};
+        sub_frame.object = shim_cast<OTIOSerializableObject>(object);
+        sub_frame.path = sub_frame_values.paths.back()->c_str();
+        sub_frame.field = field;
+        sub_frame.original_value = shim_time(time);
+        sub_frame.conformed_value = shim_time(conformed);
+        sub_frame_values.values.push_back(sub_frame);
+        return conformed;
+    }
+
+    // Conforms the start and end of range, so that whole-frame ranges stay
+    // contiguous; fields names the two values reported.
+    otio::TimeRange conform(
+            otio::TimeRange range,
+            otio::SerializableObject *object,
+            std::string const &path,
+            const char *const fields[2]) {
+        auto start = conform(range.start_time(), object, path, fields[0]);
+        auto end = conform(range.end_time_exclusive(), object, path, fields[1]);
+        return otio::TimeRange(start, end - start);
+    }
+
+    void conform_markers(otio::Item *item, std::string const &path) {
+        static const char *const fields[2] = {
+                "marked_range.start_time", "marked_range.end_time_exclusive"};
+        for (auto const &marker : item->markers()) {
+            auto marker_path = path + "/" + marker.value->name();
+            marker.value->set_marked_range(
+                    conform(marker.value->marked_range(), marker.value, marker_path, fields));
+        }
+    }
+
+    void conform_item(otio::Item *item, std::string const &path) {
+        static const char *const source_fields[2] = {
+                "source_range.start_time", "source_range.end_time_exclusive"};
+        static const char *const available_fields[2] = {
+                "available_range.start_time", "available_range.end_time_exclusive"};
+        if (auto source_range = item->source_range()) {
+            item->set_source_range(conform(*source_range, item, path, source_fields));
+        }
+        conform_markers(item, path);
+        if (auto clip = dynamic_cast<otio::Clip *>(item)) {
+            for (auto const &entry : clip->media_references()) {
+                auto reference = entry.second;
+                if (!reference || !reference->available_range()) {
+                    continue;
+                }
+                reference->set_available_range(conform(
+                        *reference->available_range(),
+                        reference,
+                        path + "/" + entry.first,
+                        available_fields));
+            }
+        }
+        auto composition = dynamic_cast<otio::Composition *>(item);
+        if (!composition) {
+            return;
+        }
+        for (auto const &child : composition->children()) {
+            auto child_path = path + "/" + child.value->name();
+            if (auto transition = dynamic_cast<otio::Transition *>(child.value)) {
+                transition->set_in_offset(conform(
+                        transition->in_offset(), transition, child_path, "in_offset"));
+                transition->set_out_offset(conform(
+                        transition->out_offset(), transition, child_path, "out_offset"));
+            } else if (auto child_item = dynamic_cast<otio::Item *>(child.value)) {
+                conform_item(child_item, child_path);
+            }
+        }
+    }
+};
+
+} // namespace
+
+int ItemAtTimeVector_size(ItemAtTimeVector *self) { return int(self->items.size()); }
+
+bool ItemAtTimeVector_empty(ItemAtTimeVector *self) { return self->items.empty(); }
+
+ItemAtTime ItemAtTimeVector_at(ItemAtTimeVector *self, int pos) {
+    return self->items.at(size_t(pos));
+}
+
+void ItemAtTimeVector_destroy(ItemAtTimeVector *self) { delete self; }
+
+ItemAtTimeVector *timeline_items_at_time(
+        Timeline *in_timeline,
+        RationalTime search_time,
+        bool skip_disabled,
+        OTIOErrorStatus *error_status) {
+    auto tracks = shim_cast<otio::Timeline>(in_timeline)->tracks();
+    return stack_items_at_time(
+            shim_cast<Stack>(tracks), search_time, skip_disabled, error_status);
+}
+
+ItemAtTimeVector *stack_items_at_time(
+        Stack *in_stack,
+        RationalTime search_time,
+        bool skip_disabled,
+        OTIOErrorStatus *error_status) {
+    auto result = new ItemAtTimeVector;
+    if (!collect_items_at_time(
+                shim_cast<otio::Stack>(in_stack),
+                shim_time(search_time),
+                skip_disabled,
+                result->items,
+                shim_cast<otio::ErrorStatus>(error_status))) {
+        delete result;
+        return nullptr;
+    }
+    return result;
+}
+
+int MarkerInTimelineVector_size(MarkerInTimelineVector *self) {
+    return int(self->markers.size());
+}
+
+bool MarkerInTimelineVector_empty(MarkerInTimelineVector *self) {
+    return self->markers.empty();
+}
+
+MarkerInTimeline MarkerInTimelineVector_at(MarkerInTimelineVector *self, int pos) {
+    return self->markers.at(size_t(pos));
+}
+
+void MarkerInTimelineVector_destroy(MarkerInTimelineVector *self) { delete self; }
+
+MarkerInTimelineVector *
+timeline_all_markers(Timeline *in_timeline, OTIOErrorStatus *error_status) {
+    auto timeline = shim_cast<otio::Timeline>(in_timeline);
+    auto stack = timeline->tracks();
+    auto global_start_time = timeline->global_start_time().value_or(otio::RationalTime());
+    auto result = new MarkerInTimelineVector;
+    if (!collect_markers(
+                stack,
+                "",
+                stack,
+                global_start_time,
+                *result,
+                shim_cast<otio::ErrorStatus>(error_status))) {
+        delete result;
+        return nullptr;
+    }
+    // The paths are only stable once collection has finished growing them.
+    for (size_t index = 0; index < result->markers.size(); index++) {
+        result->markers[index].owner_path = result->owner_paths[index].c_str();
+    }
+    return result;
+}
+
+int SubFrameValueVector_size(SubFrameValueVector *self) { return int(self->values.size()); }
+
+bool SubFrameValueVector_empty(SubFrameValueVector *self) { return self->values.empty(); }
+
+SubFrameValue SubFrameValueVector_at(SubFrameValueVector *self, int pos) {
+    return self->values.at(size_t(pos));
+}
+
+void SubFrameValueVector_destroy(SubFrameValueVector *self) { delete self; }
+
+SubFrameValueVector *timeline_conform_rate(
+        Timeline *in_timeline,
+        double new_rate,
+        OTIO_RateConform_Mode mode,
+        OpenTime_RoundingPolicy rounding_policy,
+        OTIOErrorStatus *error_status) {
+    if (!(new_rate > 0)) {
+        shim_set_error(
+                error_status, otio::ErrorStatus::INVALID_TIME_RANGE, "new_rate must be positive");
+        return nullptr;
+    }
+    auto timeline = shim_cast<otio::Timeline>(in_timeline);
+    auto result = new SubFrameValueVector;
+    RateConformer conformer{new_rate, mode, rounding_policy, *result};
+    if (auto global_start_time = timeline->global_start_time()) {
+        timeline->set_global_start_time(conformer.conform(
+                *global_start_time, timeline, timeline->name(), "global_start_time"));
+    }
+    auto stack = timeline->tracks();
+    conformer.conform_item(stack, stack->name());
+    return result;
+}
+
+namespace {
+
+// A used media range before merging.
+struct PullRange {
+    otio::TimeRange range;
+    int clip_count;
+    bool handles_clamped;
+};
+
+// Extends the clip's consumed media by the handles, without reaching past
+// the reference's available range.
+PullRange pull_range(
+        otio::TimeRange consumed,
+        otio::MediaReference *reference,
+        otio::RationalTime head_handle,
+        otio::RationalTime tail_handle) {
+    double rate = consumed.start_time().rate();
+    double start = consumed.start_time().value();
+    double end = consumed.end_time_exclusive().rescaled_to(rate).value();
+    // A zero handle may come without a rate.
+    auto frames = [rate](otio::RationalTime handle) {
+        return handle.value() == 0 ? 0 : handle.rescaled_to(rate).value();
+    };
+    double wanted_start = start - frames(head_handle);
+    double wanted_end = end + frames(tail_handle);
+    double pulled_start = wanted_start;
+    double pulled_end = wanted_end;
+    if (auto available = reference->available_range()) {
+        double available_start = available->start_time().rescaled_to(rate).value();
+        double available_end = available->end_time_exclusive().rescaled_to(rate).value();
+        pulled_start = std::max(wanted_start, std::min(start, available_start));
+        pulled_end = std::min(wanted_end, std::max(end, available_end));
+    }
+    return PullRange{
+            otio::TimeRange(
+                    otio::RationalTime(pulled_start, rate),
+                    otio::RationalTime(pulled_end - pulled_start, rate)),
+            1,
+            pulled_start != wanted_start || pulled_end != wanted_end};
+}
+
+bool collect_pulls(
+        otio::Composition *composition,
+        otio::RationalTime head_handle,
+        otio::RationalTime tail_handle,
+        bool skip_disabled,
+        std::map<std::string, std::vector<PullRange>> &pulls,
+        OTIOErrorStatus *error_status) {
+    for (auto const &child : composition->children()) {
+        auto item = dynamic_cast<otio::Item *>(child.value);
+        if (!item || (skip_disabled && !item->enabled())) {
+            continue;
+        }
+        if (auto nested = dynamic_cast<otio::Composition *>(item)) {
+            if (!collect_pulls(nested, head_handle, tail_handle, skip_disabled, pulls, error_status)) {
+                return false;
+            }
+            continue;
+        }
+        auto clip = dynamic_cast<otio::Clip *>(item);
+        if (!clip) {
+            continue;
+        }
+        auto reference = dynamic_cast<otio::ExternalReference *>(clip->media_reference());
+        if (!reference) {
+            continue;
+        }
+        auto consumed = Clip_consumed_media_range(shim_cast<Clip>(clip), error_status);
+        if (otio::is_error(shim_cast<otio::ErrorStatus>(error_status))) {
+            return false;
+        }
+        pulls[reference->target_url()].push_back(
+                pull_range(shim_range(consumed), reference, head_handle, tail_handle));
+    }
+    return true;
+}
+
+// Sorts the ranges and merges those that overlap or touch, at the rate of
+// the first.
+std::vector<PullRange> merge_pulls(std::vector<PullRange> ranges) {
+    double rate = ranges.front().range.start_time().rate();
+    for (auto &pull : ranges) {
+        pull.range = otio::TimeRange(
+                pull.range.start_time().rescaled_to(rate), pull.range.duration().rescaled_to(rate));
+    }
+    std::sort(ranges.begin(), ranges.end(), [](PullRange const &lhs, PullRange const &rhs) {
+        return lhs.range.start_time() < rhs.range.start_time();
+    });
+    std::vector<PullRange> merged;
+    for (auto const &pull : ranges) {
+        if (!merged.empty() &&
+            !(merged.back().range.end_time_exclusive() < pull.range.start_time())) {
+            auto &last = merged.back();
+            auto end = std::max(
+                    last.range.end_time_exclusive().value(),
+                    pull.range.end_time_exclusive().value());
+            last.range = otio::TimeRange(
+                    last.range.start_time(),
+                    otio::RationalTime(end - last.range.start_time().value(), rate));
+            last.clip_count += pull.clip_count;
+            last.handles_clamped = last.handles_clamped || pull.handles_clamped;
+            continue;
+        }
+        merged.push_back(pull);
+    }
+    return merged;
+}
+
+struct PullRow {
+    std::string target_url;
+    std::string start_frame;
+    std::string end_frame_exclusive;
+    std::string duration;
+    std::string rate;
+    std::string clip_count;
+    std::string handles_clamped;
+};
+
+// Frames are widened out to whole frames, so the row covers every frame the
+// entry touches. Returns false, with error_status set, when a value or the
+// rate cannot be written.
+bool pull_row(PullListEntry const &entry, PullRow *row, OTIOErrorStatus *error_status) {
+    auto range = shim_range(entry.media_range);
+    double rate = range.start_time().rate();
+    if (!std::isfinite(rate) || rate <= 0) {
+        shim_set_error(
+                error_status, otio::ErrorStatus::INVALID_TIME_RANGE,
+                "pull range has no positive finite rate");
+        return false;
+    }
+    double start = std::floor(range.start_time().value());
+    double end = std::ceil(range.end_time_exclusive().value());
+    if (!std::isfinite(start) || !std::isfinite(end)) {
+        shim_set_error(
+                error_status, otio::ErrorStatus::INVALID_TIME_RANGE,
+                "pull range is not finite");
+        return false;
+    }
+    *row = PullRow{
+            entry.target_url,
+            format_number(start),
+            format_number(end),
+            format_number(end - start),
+            format_number(rate),
+            std::to_string(entry.clip_count),
+            entry.handles_clamped ? "true" : "false"};
+    return true;
+}
+
+} // namespace
+
+int PullList_size(PullList *self) { return int(self->entries.size()); }
+
+bool PullList_empty(PullList *self) { return self->entries.empty(); }
+
+PullListEntry PullList_at(PullList *self, int pos) { return self->entries.at(size_t(pos)); }
+
+const char *PullList_to_csv_string(PullList *self, OTIOErrorStatus *error_status) {
+    std::ostringstream stream;
+    stream << "target_url,start_frame,end_frame_exclusive,duration,rate,clip_count,"
+              "handles_clamped\n";
+    for (auto const &entry : self->entries) {
+        PullRow row;
+        if (!pull_row(entry, &row, error_status)) {
+            return nullptr;
+        }
+        stream << csv_field(row.target_url) << ',' << row.start_frame << ','
+               << row.end_frame_exclusive << ',' << row.duration << ',' << row.rate << ','
+               << row.clip_count << ',' << row.handles_clamped << '\n';
+    }
+    return copy_string(stream.str());
+}
+
+const char *PullList_to_json_string(PullList *self, OTIOErrorStatus *error_status, int indent) {
+    std::string newline = indent > 0 ? "\n" : "";
+    std::string outer(size_t(std::max(indent, 0)), ' ');
+    std::string inner(size_t(std::max(indent, 0) * 2), ' ');
+    std::string separator = indent > 0 ? ": " : ":";
+    std::ostringstream stream;
+    stream << '[';
+    for (size_t index = 0; index < self->entries.size(); index++) {
+        PullRow row;
+        if (!pull_row(self->entries[index], &row, error_status)) {
+            return nullptr;
+        }
+        std::pair<char const *, std::string> fields[] = {
+                {"target_url", json_string(row.target_url)},
+                {"start_frame", row.start_frame},
+                {"end_frame_exclusive", row.end_frame_exclusive},
+                {"duration", row.duration},
+                {"rate", row.rate},
+                {"clip_count", row.clip_count},
+                {"handles_clamped", row.handles_clamped},
+        };
+        stream << (index ? "," : "") << newline << outer << '{';
+        bool first = true;
+        for (auto const &field : fields) {
+            stream << (first ? "" : ",") << newline << inner << '"' << field.first << '"'
+                   << separator << field.second;
+            first = false;
+        }
+        stream << newline << outer << '}';
+    }
+    stream << (self->entries.empty() ? "" : newline) << ']';
+    return copy_string(stream.str());
+}
+
+void PullList_destroy(PullList *self) { delete self; }
+
+PullList *timeline_pull_list(
+        Timeline *in_timeline,
+        RationalTime head_handle,
+        RationalTime tail_handle,
+        bool skip_disabled,
+        OTIOErrorStatus *error_status) {
+    if (head_handle.value < 0 || tail_handle.value < 0) {
+        shim_set_error(
+                error_status, otio::ErrorStatus::INVALID_TIME_RANGE, "handles must not be negative");
+        return nullptr;
+    }
+    auto timeline = shim_cast<otio::Timeline>(in_timeline);
+    std::map<std::string, std::vector<PullRange>> pulls;
+    if (!collect_pulls(
+                timeline->tracks(),
+                shim_time(head_handle),
+                shim_time(tail_handle),
+                skip_disabled,
+                pulls,
+                error_status)) {
+        return nullptr;
+    }
+    auto result = new PullList;
+    for (auto const &pull : pulls) {
+        result->target_urls.push_back(std::make_unique<std::string>(pull.first));
+        for (auto const &merged : merge_pulls(pull.second)) {
+            PullListEntry entry{};
+            entry.target_url = result->target_urls.back()->c_str();
+            entry.media_range = shim_range(merged.range);
+            entry.clip_count = merged.clip_count;
+            entry.handles_clamped = merged.handles_clamped;
+            result->entries.push_back(entry);
+        }
+    }
+    return result;
+}
@@ -0,0 +1,910 @@
+// Structural diff between two timelines, declared in timelineDiff.h.
+
+#include "shim.h"
+#include "export.h"
+#include "metadata.h"
+
+#include <opentimelineio/clip.h>
+#include <opentimelineio/externalReference.h>
+#include <opentimelineio/imageSequenceReference.h>
+#include <opentimelineio/stack.h>
+#include <opentimelineio/timeline.h>
+#include <opentimelineio/track.h>
+#include <opentimelineio/transition.h>
+
+#include "copentimelineio/timelineDiff.h"
+
+#include <algorithm>
+#include <cmath>
+#include <cstdlib>
+#include <cstring>
+#include <iomanip>
+#include <map>
+#include <memory>
+#include <set>
+#include <sstream>
+#include <string>
+#include <vector>
+
+struct DifferenceVector {
+    std::vector<Difference> differences;
+    std::vector<std::vector<std::string>> metadata_keys;
+    // Owned here; each Difference points into these.
+    std::vector<std::unique_ptr<std::string>> paths;
+
+    const char *own(std::string const *path) {
+        if (!path) {
+            return nullptr;
+        }
+        paths.push_back(std::make_unique<std::string>(*path));
+        return paths.back()->c_str();
+    }
+
+    void add(
+            OTIO_Difference_Kind kind,
+            int changed_fields,
+            otio::SerializableObject *old_object,
+            otio::SerializableObject *new_object,
+            std::string const *old_path,
+            std::string const *new_path,
+            std::vector<std::string> keys = {}) {
+        Difference difference{};
+        difference.kind = kind;
+        difference.changed_fields = changed_fields;
+        difference.old_object = shim_cast<OTIOSerializableObject>(old_object);
+        difference.new_object = shim_cast<OTIOSerializableObject>(new_object);
+        difference.old_path = own(old_path);
+        difference.new_path = own(new_path);
+        differences.push_back(difference);
+        metadata_keys.push_back(std::move(keys));
+    }
+};
+
+namespace {
+
+std::string join_path(std::string const &parent_path, std::string const &name) {
+    return parent_path.empty() ? name : parent_path + "/" + name;
+}
+
+std::vector<std::string> changed_metadata_keys(
+        otio::AnyDictionary &old_metadata, otio::AnyDictionary &new_metadata) {
+    std::set<std::string> keys;
+    for (auto const &entry : old_metadata) {
+        keys.insert(entry.first);
+    }
+    for (auto const &entry : new_metadata) {
+        keys.insert(entry.first);
+    }
+    std::vector<std::string> changed;
+    for (auto const &key : keys) {
+        if (!shim_same_metadata_value(old_metadata, new_metadata, key)) {
+            changed.push_back(key);
+        }
+    }
+    return changed;
+}
+
+std::string media_url(otio::MediaReference *reference) {
+    if (auto external = dynamic_cast<otio::ExternalReference *>(reference)) {
+        return external->target_url();
+    }
+    if (auto sequence = dynamic_cast<otio::ImageSequenceReference *>(reference)) {
+        return sequence->target_url_base();
+    }
+    return "";
+}
+
+template <typename T>
+bool same_objects(
+        std::vector<otio::SerializableObject::Retainer<T>> const &lhs,
+        std::vector<otio::SerializableObject::Retainer<T>> const &rhs) {
+    if (lhs.size() != rhs.size()) {
+        return false;
+    }
+    for (size_t index = 0; index < lhs.size(); index++) {
+        if (!lhs[index].value->is_equivalent_to(*rhs[index].value)) {
+            return false;
+        }
+    }
+    return true;
+}
+
+bool same_objects(otio::SerializableObject *lhs, otio::SerializableObject *rhs) {
+    if (!lhs || !rhs) {
+        return lhs == rhs;
+    }
+    return lhs->is_equivalent_to(*rhs);
+}
+
+// Returns the OTIO_Difference_Field bits that differ between a pair of
+// matched objects, leaving their children aside.
+int changed_fields(
+        otio::SerializableObjectWithMetadata *old_object,
+        otio::SerializableObjectWithMetadata *new_object,
+        std::vector<std::string> &keys) {
+    int fields = OTIO_Difference_Field_NONE;
+    if (old_object->name() != new_object->name()) {
+        fields |= OTIO_Difference_Field_NAME;
+    }
+    keys = changed_metadata_keys(old_object->metadata(), new_object->metadata());
+    if (!keys.empty()) {
+        fields |= OTIO_Difference_Field_METADATA;
+    }
+
+    auto old_item = dynamic_cast<otio::Item *>(old_object);
+    auto new_item = dynamic_cast<otio::Item *>(new_object);
+    if (old_item && new_item) {
+        if (old_item->source_range() != new_item->source_range()) {
+            fields |= OTIO_Difference_Field_SOURCE_RANGE;
+        }
+        if (old_item->enabled() != new_item->enabled() ||
+            !same_objects(old_item->effects(), new_item->effects()) ||
+            !same_objects(old_item->markers(), new_item->markers())) {
+            fields |= OTIO_Difference_Field_OTHER;
+        }
+    }
+
+    auto old_clip = dynamic_cast<otio::Clip *>(old_object);
+    auto new_clip = dynamic_cast<otio::Clip *>(new_object);
+    if (old_clip && new_clip) {
+        auto old_reference = old_clip->media_reference();
+        auto new_reference = new_clip->media_reference();
+        if (media_url(old_reference) != media_url(new_reference)) {
+            fields |= OTIO_Difference_Field_MEDIA_REFERENCE_URL;
+        } else if (!same_objects(old_reference, new_reference)) {
+            fields |= OTIO_Difference_Field_OTHER;
+        }
+    }
+
+    auto old_track = dynamic_cast<otio::Track *>(old_object);
+    auto new_track = dynamic_cast<otio::Track *>(new_object);
+    if (old_track && new_track && old_track->kind() != new_track->kind()) {
+        fields |= OTIO_Difference_Field_OTHER;
+    }
+
+    // Anything else that differs on a leaf, such as transition offsets.
+    bool composition = dynamic_cast<otio::Composition *>(old_object) != nullptr;
+    if (!composition && fields == OTIO_Difference_Field_NONE &&
+        !old_object->is_equivalent_to(*new_object)) {
+        fields |= OTIO_Difference_Field_OTHER;
+    }
+    return fields;
+}
+
+std::string match_key(otio::Composable *composable) {
+    return composable->schema_name() + "\n" + composable->name();
+}
+
+// Positions of the longest increasing run in values; the matched children
+// outside it are the ones that moved.
+std::vector<bool> longest_increasing(std::vector<size_t> const &values) {
+    std::vector<size_t> tails;
+    std::vector<size_t> previous(values.size(), values.size());
+    for (size_t index = 0; index < values.size(); index++) {
+        auto position = std::lower_bound(
+                tails.begin(), tails.end(), values[index], [&](size_t tail, size_t value) {
+                    return values[tail] < value;
+                });
+        if (position != tails.begin()) {
+            previous[index] = *(position - 1);
+        }
+        if (position == tails.end()) {
+            tails.push_back(index);
+        } else {
+            *position = index;
+        }
+    }
+    std::vector<bool> in_run(values.size(), false);
+    for (size_t index = tails.empty() ? values.size() : tails.back(); index < values.size();
+         index = previous[index]) {
+        in_run[index] = true;
+    }
+    return in_run;
+}
+
+struct Differ {
+    DifferenceVector &found;
+
+    // A removed or added child, followed in its list by its descendants,
+    // which can only be reported as moves.
+    struct Unmatched {
+        otio::Composable *object;
+        std::string path;
+        bool descendant = false;
+        // One past the last of its descendants in the list.
+        size_t end = 0;
+        bool used = false;
+    };
+    std::vector<Unmatched> removed;
+    std::vector<Unmatched> added;
+
+    static void collect(
+            std::vector<Unmatched> &list,
+            otio::Composable *object,
+            std::string const &path,
+            bool descendant) {
+        size_t position = list.size();
+        list.push_back({object, path, descendant});
+        if (auto composition = dynamic_cast<otio::Composition *>(object)) {
+            for (auto const &child : composition->children()) {
+                collect(list, child.value, join_path(path, child.value->name()), true);
+            }
+        }
+        list[position].end = list.size();
+    }
+
+    // Marks entry and its descendants as reported.
+    static void use(std::vector<Unmatched> &list, size_t index) {
+        for (size_t position = index; position < list[index].end; position++) {
+            list[position].used = true;
+        }
+    }
+
+    void compare(
+            otio::SerializableObjectWithMetadata *old_object,
+            otio::SerializableObjectWithMetadata *new_object,
+            std::string const &old_path,
+            std::string const &new_path,
+            bool moved) {
+        std::vector<std::string> keys;
+        int fields = changed_fields(old_object, new_object, keys);
+        if (moved || fields != OTIO_Difference_Field_NONE) {
+            found.add(
+                    moved ? OTIO_Difference_Kind_MOVED : OTIO_Difference_Kind_MODIFIED,
+                    fields,
+                    old_object,
+                    new_object,
+                    &old_path,
+                    &new_path,
+                    std::move(keys));
+        }
+        auto old_composition = dynamic_cast<otio::Composition *>(old_object);
+        auto new_composition = dynamic_cast<otio::Composition *>(new_object);
+        if (old_composition && new_composition) {
+            compare_children(old_composition, new_composition, old_path, new_path);
+        }
+    }
+
+    // Matches children by schema and name, in order, then pairs what is
+    // left at the same index as renames.
+    void compare_children(
+            otio::Composition *old_composition,
+            otio::Composition *new_composition,
+            std::string const &old_path,
+            std::string const &new_path) {
+        auto const &old_children = old_composition->children();
+        auto const &new_children = new_composition->children();
+        std::map<std::string, std::vector<size_t>> by_key;
+        for (size_t index = new_children.size(); index-- > 0;) {
+            by_key[match_key(new_children[index].value)].push_back(index);
+        }
+        std::vector<size_t> match(old_children.size(), new_children.size());
+        std::vector<bool> new_matched(new_children.size(), false);
+        for (size_t index = 0; index < old_children.size(); index++) {
+            auto &candidates = by_key[match_key(old_children[index].value)];
+            if (!candidates.empty()) {
+                match[index] = candidates.back();
+                new_matched[candidates.back()] = true;
+                candidates.pop_back();
+            }
+        }
+        for (size_t index = 0; index < old_children.size() && index < new_children.size();
+             index++) {
+            if (match[index] == new_children.size() && !new_matched[index] &&
+                old_children[index].value->schema_name() ==
+                        new_children[index].value->schema_name()) {
+                match[index] = index;
+                new_matched[index] = true;
+            }
+        }
+
+        std::vector<size_t> matched_old;
+        std::vector<size_t> matched_new;
+        for (size_t index = 0; index < old_children.size(); index++) {
+            if (match[index] != new_children.size()) {
+                matched_old.push_back(index);
+                matched_new.push_back(match[index]);
+            }
+        }
+        auto in_order = longest_increasing(matched_new);
+        for (size_t index = 0; index < old_children.size(); index++) {
+            auto child = old_children[index].value;
+            if (match[index] == new_children.size()) {
+                collect(removed, child, join_path(old_path, child->name()), false);
+            }
+        }
+        for (size_t position = 0; position < matched_old.size(); position++) {
+            auto old_child = old_children[matched_old[position]].value;
+            auto new_child = new_children[matched_new[position]].value;
+            compare(old_child,
+                    new_child,
+                    join_path(old_path, old_child->name()),
+                    join_path(new_path, new_child->name()),
+                    !in_order[position]);
+        }
+        for (size_t index = 0; index < new_children.size(); index++) {
+            auto child = new_children[index].value;
+            if (!new_matched[index]) {
+                collect(added, child, join_path(new_path, child->name()), false);
+            }
+        }
+    }
+
+    // Pairs children that left one composition and appeared in another as
+    // moves, including those inside removed or added compositions, then
+    // reports the rest as removed or added.
+    void finish() {
+        bool paired = true;
+        while (paired) {
+            paired = false;
+            for (size_t old_index = 0; old_index < removed.size(); old_index++) {
+                if (removed[old_index].used) {
+                    continue;
+                }
+                for (size_t new_index = 0; new_index < added.size(); new_index++) {
+                    if (added[new_index].used ||
+                        match_key(added[new_index].object) !=
+                                match_key(removed[old_index].object)) {
+                        continue;
+                    }
+                    use(removed, old_index);
+                    use(added, new_index);
+                    // Copied, as comparing may grow both lists.
+                    auto old_entry = removed[old_index];
+                    auto new_entry = added[new_index];
+                    compare(old_entry.object,
+                            new_entry.object,
+                            old_entry.path,
+                            new_entry.path,
+                            true);
+                    paired = true;
+                    break;
+                }
+            }
+        }
+        for (auto const &entry : removed) {
+            if (!entry.used && !entry.descendant) {
+                found.add(
+                        OTIO_Difference_Kind_REMOVED,
+                        OTIO_Difference_Field_NONE,
+                        entry.object,
+                        nullptr,
+                        &entry.path,
+                        nullptr);
+            }
+        }
+        for (auto const &entry : added) {
+            if (!entry.used && !entry.descendant) {
+                found.add(
+                        OTIO_Difference_Kind_ADDED,
+                        OTIO_Difference_Field_NONE,
+                        nullptr,
+                        entry.object,
+                        nullptr,
+                        &entry.path);
+            }
+        }
+    }
+};
+
+// A clip of a cut with its record range, in timeline time including the
+// timeline's global_start_time.
+struct CutClip {
+    otio::Clip *clip;
+    otio::TimeRange source_range;
+    otio::TimeRange record_range;
+    otio::optional<std::string> key;
+};
+
+otio::optional<std::string> cut_key(
+        otio::Clip *clip, OTIO_CutChange_MatchBy match_by, std::string const &metadata_key) {
+    switch (match_by) {
+        case OTIO_CutChange_MatchBy_NAME:
+            return clip->name();
+        case OTIO_CutChange_MatchBy_MEDIA_URL: {
+            auto url = media_url(clip->media_reference());
+            if (url.empty()) {
+                return {};
+            }
+            return url;
+        }
+        case OTIO_CutChange_MatchBy_METADATA_KEY: {
+            auto &metadata = clip->metadata();
+            auto value = metadata.find(metadata_key);
+            if (value == metadata.end()) {
+                return {};
+            }
+            if (auto text = otio::any_cast<std::string>(&value->second)) {
+                return *text;
+            }
+            return {};
+        }
+    }
+    return {};
+}
+
+bool collect_cut_clips(
+        otio::Composition *composition,
+        otio::Stack *stack,
+        otio::RationalTime global_start_time,
+        OTIO_CutChange_MatchBy match_by,
+        std::string const &metadata_key,
+        std::vector<CutClip> &clips,
+        otio::ErrorStatus *error_status) {
+    for (auto const &child : composition->children()) {
+        if (auto nested = dynamic_cast<otio::Composition *>(child.value)) {
+            if (!collect_cut_clips(
+                        nested,
+                        stack,
+                        global_start_time,
+                        match_by,
+                        metadata_key,
+                        clips,
+                        error_status)) {
+                return false;
+            }
+            continue;
+        }
+        auto clip = dynamic_cast<otio::Clip *>(child.value);
+        if (!clip) {
+            continue;
+        }
+        auto source_range = clip->trimmed_range(error_status);
+        if (otio::is_error(error_status)) {
+            return false;
+        }
+        auto range = clip->transformed_time_range(source_range, stack, error_status);
+        if (otio::is_error(error_status)) {
+            return false;
+        }
+        auto record_range = otio::TimeRange(
+                range.start_time() + global_start_time.rescaled_to(range.start_time().rate()),
+                range.duration());
+        clips.push_back({clip, source_range, record_range, cut_key(clip, match_by, metadata_key)});
+    }
+    return true;
+}
+
+// Collects the clips of the timeline's top-level tracks of track_kind.
+bool collect_cut_clips(
+        otio::Timeline *timeline,
+        std::string const &track_kind,
+        OTIO_CutChange_MatchBy match_by,
+        std::string const &metadata_key,
+        std::vector<CutClip> &clips,
+        otio::ErrorStatus *error_status) {
+    auto stack = timeline->tracks();
+    auto global_start_time = timeline->global_start_time().value_or(otio::RationalTime());
+    for (auto const &child : stack->children()) {
+        auto track = dynamic_cast<otio::Track *>(child.value);
+        if (!track || track->kind() != track_kind) {
+            continue;
+        }
+        if (!collect_cut_clips(
+                    track,
+                    stack,
+                    global_start_time,
+                    match_by,
+                    metadata_key,
+                    clips,
+                    error_status)) {
+            return false;
+        }
+    }
+    return true;
+}
+
+CutChange cut_change(OTIO_CutChange_Kind kind, CutClip const *old_clip, CutClip const *new_clip) {
+    CutChange change{};
+    change.kind = kind;
+    if (old_clip) {
+        change.old_clip = shim_cast<Clip>(old_clip->clip);
+        change.old_range_in_timeline.value = shim_range(old_clip->record_range);
+        change.old_range_in_timeline.valid = true;
+    }
+    if (new_clip) {
+        change.new_clip = shim_cast<Clip>(new_clip->clip);
+        change.new_range_in_timeline.value = shim_range(new_clip->record_range);
+        change.new_range_in_timeline.valid = true;
+    }
+    return change;
+}
+
+const char *cut_change_kind_name(OTIO_CutChange_Kind kind) {
+    switch (kind) {
+        case OTIO_CutChange_Kind_INSERT:
+            return "INSERT";
+        case OTIO_CutChange_Kind_OMIT:
+            return "OMIT";
+        case OTIO_CutChange_Kind_TRIM:
+            return "TRIM";
+        case OTIO_CutChange_Kind_SLIP:
+            return "SLIP";
+        case OTIO_CutChange_Kind_MOVE:
+            return "MOVE";
+    }
+    return "";
+}
+
+// The exported fields of one change, as strings. Record ranges become
+// timecodes at rate, with an exclusive out point; deltas become frames.
+struct CutChangeRow {
+    std::string kind;
+    std::string old_clip;
+    std::string new_clip;
+    std::string old_record_in;
+    std::string old_record_out;
+    std::string new_record_in;
+    std::string new_record_out;
+    std::string head_delta;
+    std::string tail_delta;
+};
+
+bool timecode(
+        otio::RationalTime time,
+        double rate,
+        std::string &result,
+        otio::ErrorStatus *error_status) {
+    opentime::OPENTIME_VERSION::ErrorStatus timecode_status;
+    result = time.to_timecode(
+            rate, opentime::OPENTIME_VERSION::IsDropFrameRate::InferFromRate, &timecode_status);
+    if (timecode_status.outcome != opentime::OPENTIME_VERSION::ErrorStatus::OK) {
+        if (error_status) {
+            *error_status = otio::ErrorStatus(
+                    otio::ErrorStatus::INVALID_TIME_RANGE,
+                    "cannot express record time as timecode: " + timecode_status.details);
+        }
+        return false;
+    }
+    return true;
+}
+
+std::string frames(RationalTime delta, double rate) {
+    return format_number(shim_time(delta).rescaled_to(rate).value());
+}
+
+bool cut_change_row(
+        CutChange const &change,
+        double rate,
+        CutChangeRow &row,
+        otio::ErrorStatus *error_status) {
+    row.kind = cut_change_kind_name(change.kind);
+    if (change.old_clip) {
+        row.old_clip = shim_cast<otio::Clip>(change.old_clip)->name();
+        auto range = shim_range(change.old_range_in_timeline.value);
+        if (!timecode(range.start_time(), rate, row.old_record_in, error_status) ||
+            !timecode(range.end_time_exclusive(), rate, row.old_record_out, error_status)) {
+            return false;
+        }
+    }
+    if (change.new_clip) {
+        row.new_clip = shim_cast<otio::Clip>(change.new_clip)->name();
+        auto range = shim_range(change.new_range_in_timeline.value);
+        if (!timecode(range.start_time(), rate, row.new_record_in, error_status) ||
+            !timecode(range.end_time_exclusive(), rate, row.new_record_out, error_status)) {
+            return false;
+        }
+    }
+    row.head_delta = frames(change.head_delta, rate);
+    row.tail_delta = frames(change.tail_delta, rate);
+    return true;
+}
+
+} // namespace
+
+int DifferenceVector_size(DifferenceVector *self) { return int(self->differences.size()); }
+
+bool DifferenceVector_empty(DifferenceVector *self) { return self->differences.empty(); }
+
+Difference DifferenceVector_at(DifferenceVector *self, int pos) {
+    return self->differences.at(size_t(pos));
+}
+
+int DifferenceVector_changed_metadata_key_count(DifferenceVector *self, int pos) {
+    return int(self->metadata_keys.at(size_t(pos)).size());
+}
+
+const char *
+DifferenceVector_changed_metadata_key(DifferenceVector *self, int pos, int key_index) {
+    return self->metadata_keys.at(size_t(pos)).at(size_t(key_index)).c_str();
+}
+
+void DifferenceVector_destroy(DifferenceVector *self) { delete self; }
+
+DifferenceVector *diff_serializable_objects(
+        OTIOSerializableObject *old_object,
+        OTIOSerializableObject *new_object,
+        OTIOErrorStatus *error_status) {
+    if (!old_object || !new_object) {
+        shim_set_error(
+                error_status, otio::ErrorStatus::TYPE_MISMATCH, "cannot diff a null object");
+        return nullptr;
+    }
+    auto old_timeline = dynamic_cast<otio::Timeline *>(
+            shim_cast<otio::SerializableObject>(old_object));
+    auto new_timeline = dynamic_cast<otio::Timeline *>(
+            shim_cast<otio::SerializableObject>(new_object));
+    if (old_timeline && new_timeline) {
+        return diff_timelines(
+                shim_cast<Timeline>(old_timeline), shim_cast<Timeline>(new_timeline), error_status);
+    }
+
+    auto result = new DifferenceVector;
+    auto old_with_metadata = dynamic_cast<otio::SerializableObjectWithMetadata *>(
+            shim_cast<otio::SerializableObject>(old_object));
+    auto new_with_metadata = dynamic_cast<otio::SerializableObjectWithMetadata *>(
+            shim_cast<otio::SerializableObject>(new_object));
+    if (old_with_metadata && new_with_metadata &&
+        old_with_metadata->schema_name() == new_with_metadata->schema_name()) {
+        Differ differ{*result, {}, {}};
+        differ.compare(
+                old_with_metadata,
+                new_with_metadata,
+                old_with_metadata->name(),
+                new_with_metadata->name(),
+                false);
+        differ.finish();
+        return result;
+    }
+    auto old_plain = shim_cast<otio::SerializableObject>(old_object);
+    auto new_plain = shim_cast<otio::SerializableObject>(new_object);
+    if (old_plain->schema_name() == new_plain->schema_name()) {
+        if (!old_plain->is_equivalent_to(*new_plain)) {
+            std::string path;
+            result->add(
+                    OTIO_Difference_Kind_MODIFIED,
+                    OTIO_Difference_Field_OTHER,
+                    old_plain,
+                    new_plain,
+                    &path,
+                    &path);
+        }
+        return result;
+    }
+    std::string old_path = old_with_metadata ? old_with_metadata->name() : "";
+    std::string new_path = new_with_metadata ? new_with_metadata->name() : "";
+    result->add(
+            OTIO_Difference_Kind_REMOVED,
+            OTIO_Difference_Field_NONE,
+            old_plain,
+            nullptr,
+            &old_path,
+            nullptr);
+    result->add(
+            OTIO_Difference_Kind_ADDED,
+            OTIO_Difference_Field_NONE,
+            nullptr,
+            new_plain,
+            nullptr,
+            &new_path);
+    return result;
+}
+
+DifferenceVector *diff_timelines(
+        Timeline *old_timeline, Timeline *new_timeline, OTIOErrorStatus *error_status) {
+    auto old_object = shim_cast<otio::Timeline>(old_timeline);
+    auto new_object = shim_cast<otio::Timeline>(new_timeline);
+    if (!old_object || !new_object) {
+        shim_set_error(
+                error_status, otio::ErrorStatus::TYPE_MISMATCH, "cannot diff a null timeline");
+        return nullptr;
+    }
+    if (!old_object->tracks() || !new_object->tracks()) {
+        shim_set_error(
+                error_status,
+                otio::ErrorStatus::TYPE_MISMATCH,
+                "cannot diff a timeline without tracks");
+        return nullptr;
+    }
+    auto result = new DifferenceVector;
+
+    std::vector<std::string> keys;
+    int fields = OTIO_Difference_Field_NONE;
+    if (old_object->name() != new_object->name()) {
+        fields |= OTIO_Difference_Field_NAME;
+    }
+    keys = changed_metadata_keys(old_object->metadata(), new_object->metadata());
+    if (!keys.empty()) {
+        fields |= OTIO_Difference_Field_METADATA;
+    }
+    if (old_object->global_start_time() != new_object->global_start_time()) {
+        fields |= OTIO_Difference_Field_OTHER;
+    }
+    if (fields != OTIO_Difference_Field_NONE) {
+        auto old_path = old_object->name();
+        auto new_path = new_object->name();
+        result->add(
+                OTIO_Difference_Kind_MODIFIED,
+                fields,
+                old_object,
+                new_object,
+                &old_path,
+                &new_path,
+                std::move(keys));
+    }
+
+    Differ differ{*result, {}, {}};
+    auto old_stack = old_object->tracks();
+    auto new_stack = new_object->tracks();
+    differ.compare(old_stack, new_stack, old_stack->name(), new_stack->name(), false);
+    differ.finish();
+    return result;
+}
+
+struct CutChangeList {
+    std::vector<CutChange> changes;
+};
+
+int CutChangeList_size(CutChangeList *self) { return int(self->changes.size()); }
+
+bool CutChangeList_empty(CutChangeList *self) { return self->changes.empty(); }
+
+CutChange CutChangeList_at(CutChangeList *self, int pos) {
+    return self->changes.at(size_t(pos));
+}
+
+const char *
+CutChangeList_to_csv_string(CutChangeList *self, double rate, OTIOErrorStatus *error_status) {
+    std::ostringstream stream;
+    stream << "kind,old_clip,new_clip,old_record_in,old_record_out,new_record_in,"
+              "new_record_out,head_delta,tail_delta\n";
+    for (auto const &change : self->changes) {
+        CutChangeRow row;
+        if (!cut_change_row(change, rate, row, shim_cast<otio::ErrorStatus>(error_status))) {
+            return nullptr;
+        }
+        stream << row.kind << ',' << csv_field(row.old_clip) << ',' << csv_field(row.new_clip)
+               << ',' << row.old_record_in << ',' << row.old_record_out << ','
+               << row.new_record_in << ',' << row.new_record_out << ',' << row.head_delta
+               << ',' << row.tail_delta << '\n';
+    }
+    return copy_string(stream.str());
+}
+
+const char *CutChangeList_to_json_string(
+        CutChangeList *self, double rate, OTIOErrorStatus *error_status, int indent) {
+    std::string newline = indent > 0 ? "\n" : "";
+    std::string outer(size_t(std::max(indent, 0)), ' ');
+    std::string inner(size_t(std::max(indent, 0) * 2), ' ');
+    std::string separator = indent > 0 ? ": " : ":";
+    std::ostringstream stream;
+    stream << '[';
+    for (size_t index = 0; index < self->changes.size(); index++) {
+        auto const &change = self->changes[index];
+        CutChangeRow row;
+        if (!cut_change_row(change, rate, row, shim_cast<otio::ErrorStatus>(error_status))) {
+            return nullptr;
+        }
+        auto text_or_null = [](bool present, std::string const &value) {
+            return present ? json_string(value) : std::string("null");
+        };
+        std::pair<char const *, std::string> fields[] = {
+                {"kind", json_string(row.kind)},
+                {"old_clip", text_or_null(change.old_clip, row.old_clip)},
+                {"new_clip", text_or_null(change.new_clip, row.new_clip)},
+                {"old_record_in", text_or_null(change.old_clip, row.old_record_in)},
+                {"old_record_out", text_or_null(change.old_clip, row.old_record_out)},
+                {"new_record_in", text_or_null(change.new_clip, row.new_record_in)},
+                {"new_record_out", text_or_null(change.new_clip, row.new_record_out)},
+                {"head_delta", row.head_delta},
+                {"tail_delta", row.tail_delta},
+        };
+        stream << (index ? "," : "") << newline << outer << '{';
+        bool first = true;
+        for (auto const &field : fields) {
+            stream << (first ? "" : ",") << newline << inner << '"' << field.first << '"'
+                   << separator << field.second;
+            first = false;
+        }
+        stream << newline << outer << '}';
+    }
+    stream << (self->changes.empty() ? "" : newline) << ']';
+    return copy_string(stream.str());
+}
+
+void CutChangeList_destroy(CutChangeList *self) { delete self; }
+
+CutChangeList *compare_cuts(
+        Timeline *old_timeline,
+        Timeline *new_timeline,
+        const char *track_kind,
+        OTIO_CutChange_MatchBy match_by,
+        const char *metadata_key,
+        OTIOErrorStatus *error_status) {
+    auto status = shim_cast<otio::ErrorStatus>(error_status);
+    std::string kind = track_kind ? track_kind : otio::Track::Kind::video;
+    std::string key = metadata_key ? metadata_key : "";
+    std::vector<CutClip> old_clips;
+    std::vector<CutClip> new_clips;
+    if (!collect_cut_clips(
+                shim_cast<otio::Timeline>(old_timeline),
+                kind,
+                match_by,
+                key,
+                old_clips,
+                status) ||
+        !collect_cut_clips(
+                shim_cast<otio::Timeline>(new_timeline),
+                kind,
+                match_by,
+                key,
+                new_clips,
+                status)) {
+        return nullptr;
+    }
+
+    // Clips with the same key are matched in cut order.
+    std::map<std::string, std::vector<size_t>> by_key;
+    for (size_t index = new_clips.size(); index-- > 0;) {
+        if (new_clips[index].key) {
+            by_key[*new_clips[index].key].push_back(index);
+        }
+    }
+    std::vector<size_t> matched_old;
+    std::vector<size_t> matched_new;
+    std::vector<bool> new_matched(new_clips.size(), false);
+    auto result = new CutChangeList;
+    for (size_t index = 0; index < old_clips.size(); index++) {
+        auto const &old_clip = old_clips[index];
+        auto candidates = old_clip.key ? by_key.find(*old_clip.key) : by_key.end();
+        if (candidates == by_key.end() || candidates->second.empty()) {
+            result->changes.push_back(cut_change(OTIO_CutChange_Kind_OMIT, &old_clip, nullptr));
+            continue;
+        }
+        matched_old.push_back(index);
+        matched_new.push_back(candidates->second.back());
+        new_matched[candidates->second.back()] = true;
+        candidates->second.pop_back();
+    }
+    for (size_t index = 0; index < new_clips.size(); index++) {
+        if (!new_matched[index]) {
+            result->changes.push_back(
+                    cut_change(OTIO_CutChange_Kind_INSERT, nullptr, &new_clips[index]));
+        }
+    }
+
+    auto in_order = longest_increasing(matched_new);
+    for (size_t position = 0; position < matched_old.size(); position++) {
+        auto const &old_clip = old_clips[matched_old[position]];
+        auto const &new_clip = new_clips[matched_new[position]];
+        if (!in_order[position]) {
+            result->changes.push_back(cut_change(OTIO_CutChange_Kind_MOVE, &old_clip, &new_clip));
+        }
+        auto rate = old_clip.source_range.start_time().rate();
+        // Positive deltas mean the new cut uses more media on that side.
+        // They are whole frames at the old clip's rate, so that rescaling
+        // noise neither reports a change nor hides a slip.
+        double head_delta = std::round(
+                (old_clip.source_range.start_time() -
+                 new_clip.source_range.start_time().rescaled_to(rate))
+                        .value());
+        double tail_delta = std::round(
+                (new_clip.source_range.end_time_exclusive().rescaled_to(rate) -
+                 old_clip.source_range.end_time_exclusive())
+                        .value());
+        if (head_delta == 0 && tail_delta == 0) {
+            continue;
+        }
+        bool slip = head_delta == -tail_delta;
+        auto change = cut_change(
+                slip ? OTIO_CutChange_Kind_SLIP : OTIO_CutChange_Kind_TRIM, &old_clip, &new_clip);
+        change.head_delta = RationalTime{head_delta, rate};
+        change.tail_delta = RationalTime{tail_delta, rate};
+        result->changes.push_back(change);
+    }
+
+    // Reported in record order of the new cut; omits at their old position.
+    std::stable_sort(
+            result->changes.begin(),
+            result->changes.end(),
+            [](CutChange const &lhs, CutChange const &rhs) {
+                auto start = [](CutChange const &change) {
+                    auto const &range = change.new_range_in_timeline.valid
+                                                ? change.new_range_in_timeline
+                                                : change.old_range_in_timeline;
+                    return shim_time(range.value.start_time).to_seconds();
+                };
+                return start(lhs) < start(rhs);
+            });
+    return result;
+}
@@ -0,0 +1,174 @@
+// Interval index over the items of a timeline's top-level tracks.
+
+#include "shim.h"
+
+#include <opentimelineio/stack.h>
+#include <opentimelineio/timeline.h>
+#include <opentimelineio/track.h>
+
+#include "copentimelineio/timelineIndex.h"
+
+#include <algorithm>
+#include <limits>
+#include <vector>
+
+struct IndexedItemVector {
+    std::vector<IndexedItem> items;
+};
+
+struct TimelineIndex {
+    otio::SerializableObject::Retainer<otio::Timeline> timeline;
+    // Sorted by start time, then top-down in stack order. The ranges are
+    // cached in seconds so queries need no rate conversions.
+    std::vector<IndexedItem> items;
+    std::vector<double> starts;
+    std::vector<double> ends;
+    // max_ends[i] is the largest end in the implicit balanced subtree rooted
+    // at i, the midpoint of the slice that contains it.
+    std::vector<double> max_ends;
+
+    double build_max_ends(size_t begin, size_t end) {
+        if (begin >= end) {
+            return -std::numeric_limits<double>::infinity();
+        }
+        size_t mid = begin + (end - begin) / 2;
+        max_ends[mid] = std::max(
+                {ends[mid], build_max_ends(begin, mid), build_max_ends(mid + 1, end)});
+        return max_ends[mid];
+    }
+
+    // Appends, in order, the items whose [start, end) overlaps
+    // [query_start, query_end); a point query passes equal bounds.
+    void query(
+            size_t begin,
+            size_t end,
+            double query_start,
+            double query_end,
+            std::vector<IndexedItem> &found) const {
+        if (begin >= end) {
+            return;
+        }
+        size_t mid = begin + (end - begin) / 2;
+        if (max_ends[mid] <= query_start) {
+            return;
+        }
+        query(begin, mid, query_start, query_end, found);
+        bool point = query_start == query_end;
+        if (point ? starts[mid] > query_start : starts[mid] >= query_end) {
+            return;
+        }
+        if (ends[mid] > query_start) {
+            found.push_back(items[mid]);
+        }
+        query(mid + 1, end, query_start, query_end, found);
+    }
+
+    // On failure the index is left empty.
+    bool rebuild(otio::ErrorStatus *error_status) {
+        items.clear();
+        starts.clear();
+        ends.clear();
+        max_ends.clear();
+        auto stack = timeline->tracks();
+        auto const &tracks = stack->children();
+        for (size_t n = 0; n < tracks.size(); n++) {
+            size_t track_index = tracks.size() - 1 - n;
+            auto track = dynamic_cast<otio::Track *>(tracks[track_index].value);
+            if (!track) {
+                continue;
+            }
+            auto track_range = stack->range_of_child_at_index(int(track_index), error_status);
+            if (otio::is_error(error_status)) {
+                return false;
+            }
+            auto trimmed_range = track->trimmed_range(error_status);
+            if (otio::is_error(error_status)) {
+                return false;
+            }
+            auto offset = track_range.start_time() - trimmed_range.start_time();
+            auto child_ranges = track->range_of_all_children(error_status);
+            if (otio::is_error(error_status)) {
+                return false;
+            }
+            // Walk the children in track order rather than the map's
+            // pointer order, so that the index does not depend on addresses.
+            for (auto const &child : track->children()) {
+                auto item = dynamic_cast<otio::Item *>(child.value);
+                if (!item) {
+                    continue;
+                }
+                auto const &child_range = child_ranges.at(child.value);
+                IndexedItem indexed{};
+                indexed.item = shim_cast<Item>(item);
+                indexed.track = shim_cast<Track>(track);
+                indexed.range = shim_range(otio::TimeRange(
+                        child_range.start_time() + offset, child_range.duration()));
+                items.push_back(indexed);
+            }
+        }
+        // Items were gathered top-down, so a stable sort keeps stack order
+        // among items that start together.
+        std::stable_sort(
+                items.begin(), items.end(), [](IndexedItem const &lhs, IndexedItem const &rhs) {
+                    return shim_range(lhs.range).start_time() <
+                           shim_range(rhs.range).start_time();
+                });
+        for (auto const &indexed : items) {
+            auto range = shim_range(indexed.range);
+            starts.push_back(range.start_time().to_seconds());
+            ends.push_back(range.end_time_exclusive().to_seconds());
+        }
+        max_ends.assign(items.size(), 0);
+        build_max_ends(0, items.size());
+        return true;
+    }
+};
+
+int IndexedItemVector_size(IndexedItemVector *self) { return int(self->items.size()); }
+
+bool IndexedItemVector_empty(IndexedItemVector *self) { return self->items.empty(); }
+
+IndexedItem IndexedItemVector_at(IndexedItemVector *self, int pos) {
+    return self->items.at(size_t(pos));
+}
+
+void IndexedItemVector_destroy(IndexedItemVector *self) { delete self; }
+
+TimelineIndex *TimelineIndex_create(Timeline *timeline, OTIOErrorStatus *error_status) {
+    auto index = new TimelineIndex;
+    index->timeline = otio::SerializableObject::Retainer<otio::Timeline>(
+            shim_cast<otio::Timeline>(timeline));
+    if (!index->rebuild(shim_cast<otio::ErrorStatus>(error_status))) {
+        delete index;
+        return nullptr;
+    }
+    return index;
+}
+
+void TimelineIndex_destroy(TimelineIndex *self) { delete self; }
+
+bool TimelineIndex_rebuild(TimelineIndex *self, OTIOErrorStatus *error_status) {
+    return self->rebuild(shim_cast<otio::ErrorStatus>(error_status));
+}
+
+int TimelineIndex_size(TimelineIndex *self) { return int(self->items.size()); }
+
+IndexedItemVector *TimelineIndex_items_at_time(TimelineIndex *self, RationalTime time) {
+    auto found = new IndexedItemVector;
+    double seconds = shim_time(time).to_seconds();
+    self->query(0, self->items.size(), seconds, seconds, found->items);
+    return found;
+}
+
+IndexedItemVector *
+TimelineIndex_items_overlapping_range(TimelineIndex *self, TimeRange time_range) {
+    auto found = new IndexedItemVector;
+    auto range = shim_range(time_range);
+    double start = range.start_time().to_seconds();
+    double end = range.end_time_exclusive().to_seconds();
+    // An empty range has nothing to overlap.
+    if (end > start) {
+        self->query(0, self->items.size(), start, end, found->items);
+    }
+    return found;
+}
@@ -0,0 +1,599 @@
+// Three-way merge of timelines, declared in timelineMerge.h.
+
+#include "shim.h"
+#include "metadata.h"
+
+#include <opentimelineio/stack.h>
+#include <opentimelineio/timeline.h>
+#include <opentimelineio/track.h>
+
+#include "copentimelineio/timelineMerge.h"
+
+#include <algorithm>
+#include <map>
+#include <memory>
+#include <set>
+#include <string>
+#include <vector>
+
+struct MergeConflictVector {
+    std::vector<MergeConflict> conflicts;
+};
+
+struct MergeResult {
+    otio::SerializableObject::Retainer<> merged_object;
+    std::vector<MergeConflict> conflicts;
+    // Owned here; each MergeConflict points into these.
+    std::vector<std::unique_ptr<std::string>> paths;
+
+    void add(
+            OTIO_MergeConflict_Kind kind,
+            otio::SerializableObject *base_object,
+            otio::SerializableObject *ours_object,
+            otio::SerializableObject *theirs_object,
+            std::string const &path) {
+        paths.push_back(std::make_unique<std::string>(path));
+        MergeConflict conflict{};
+        conflict.kind = kind;
+        conflict.base_object = shim_cast<OTIOSerializableObject>(base_object);
+        conflict.ours_object = shim_cast<OTIOSerializableObject>(ours_object);
+        conflict.theirs_object = shim_cast<OTIOSerializableObject>(theirs_object);
+        conflict.path = paths.back()->c_str();
+        conflicts.push_back(conflict);
+    }
+};
+
+namespace {
+
+using Object = otio::SerializableObjectWithMetadata;
+
+constexpr size_t missing = size_t(-1);
+
+std::string join_path(std::string const &parent_path, std::string const &name) {
+    return parent_path.empty() ? name : parent_path + "/" + name;
+}
+
+template <typename T>
+T *clone_of(T *object, otio::ErrorStatus *error_status) {
+    return dynamic_cast<T *>(object->clone(error_status));
+}
+
+template <typename T>
+bool same_objects(
+        std::vector<otio::SerializableObject::Retainer<T>> const &lhs,
+        std::vector<otio::SerializableObject::Retainer<T>> const &rhs) {
+    if (lhs.size() != rhs.size()) {
+        return false;
+    }
+    for (size_t index = 0; index < lhs.size(); index++) {
+        if (!lhs[index].value->is_equivalent_to(*rhs[index].value)) {
+            return false;
+        }
+    }
+    return true;
+}
+
+enum class Pick { OURS, THEIRS, CONFLICT };
+
+// Takes theirs only when they alone changed a field.
+Pick pick(bool ours_changed, bool theirs_changed, bool same_change) {
+    if (!theirs_changed || same_change) {
+        return Pick::OURS;
+    }
+    return ours_changed ? Pick::CONFLICT : Pick::THEIRS;
+}
+
+template <typename T>
+Pick pick(T const &base, T const &ours, T const &theirs) {
+    return pick(!(ours == base), !(theirs == base), ours == theirs);
+}
+
+// Returns children matched to base children by schema and name, in order,
+// as the base index of each child or missing for additions.
+std::vector<size_t> match_to_base(otio::Composition *base, otio::Composition *side) {
+    auto key = [](otio::Composable *child) {
+        return child->schema_name() + "\n" + child->name();
+    };
+    auto const &base_children = base->children();
+    std::map<std::string, std::vector<size_t>> by_key;
+    for (size_t index = base_children.size(); index-- > 0;) {
+        by_key[key(base_children[index].value)].push_back(index);
+    }
+    std::vector<size_t> matched;
+    for (auto const &child : side->children()) {
+        auto &candidates = by_key[key(child.value)];
+        if (candidates.empty()) {
+            matched.push_back(missing);
+        } else {
+            matched.push_back(candidates.back());
+            candidates.pop_back();
+        }
+    }
+    return matched;
+}
+
+// A child of the merged composition: a base child kept by both sides, or a
+// child only one side has.
+struct Token {
+    enum Side { BASE, OURS, THEIRS } side;
+    size_t index;
+
+    bool operator==(Token const &other) const {
+        return side == other.side && index == other.index;
+    }
+};
+
+struct Merger {
+    MergeResult &result;
+    otio::ErrorStatus *error_status;
+
+    // Sets same to whether lhs and rhs agree in everything but name,
+    // metadata, source range and children; fails if either cannot be cloned.
+    // Compositions and timelines are compared field by field rather than
+    // cloned, so that each level costs nothing for the subtree below it.
+    bool same_other(Object *lhs, Object *rhs, bool &same) {
+        if (dynamic_cast<otio::Composition *>(lhs) || dynamic_cast<otio::Timeline *>(lhs)) {
+            same = same_container_fields(lhs, rhs);
+            return true;
+        }
+        otio::SerializableObject::Retainer<Object> stripped[2] = {
+                clone_of(lhs, error_status), clone_of(rhs, error_status)};
+        for (auto const &object : stripped) {
+            if (!object) {
+                return false;
+            }
+            object->set_name("");
+            object->metadata().clear();
+            if (auto item = dynamic_cast<otio::Item *>(object.value)) {
+                item->set_source_range({});
+            }
+            if (auto composition = dynamic_cast<otio::Composition *>(object.value)) {
+                composition->clear_children();
+            }
+            if (auto timeline = dynamic_cast<otio::Timeline *>(object.value)) {
+                timeline->set_tracks(new otio::Stack);
+            }
+        }
+        same = stripped[0]->is_equivalent_to(*stripped[1].value);
+        return true;
+    }
+
+    static bool same_container_fields(Object *lhs, Object *rhs) {
+        if (lhs->schema_name() != rhs->schema_name()) {
+            return false;
+        }
+        auto lhs_item = dynamic_cast<otio::Item *>(lhs);
+        auto rhs_item = dynamic_cast<otio::Item *>(rhs);
+        if (lhs_item && rhs_item &&
+            (lhs_item->enabled() != rhs_item->enabled() ||
+             !same_objects(lhs_item->effects(), rhs_item->effects()) ||
+             !same_objects(lhs_item->markers(), rhs_item->markers()))) {
+            return false;
+        }
+        auto lhs_track = dynamic_cast<otio::Track *>(lhs);
+        auto rhs_track = dynamic_cast<otio::Track *>(rhs);
+        if (lhs_track && rhs_track && lhs_track->kind() != rhs_track->kind()) {
+            return false;
+        }
+        auto lhs_timeline = dynamic_cast<otio::Timeline *>(lhs);
+        auto rhs_timeline = dynamic_cast<otio::Timeline *>(rhs);
+        return !lhs_timeline || !rhs_timeline ||
+               lhs_timeline->global_start_time() == rhs_timeline->global_start_time();
+    }
+
+    // Merges the name and metadata of base, ours and theirs into merged and
+    // returns whether both sides changed either differently.
+    bool merge_named(Object *base, Object *ours, Object *theirs, Object *merged) {
+        bool conflicted = false;
+        switch (pick(base->name(), ours->name(), theirs->name())) {
+            case Pick::THEIRS:
+                merged->set_name(theirs->name());
+                break;
+            case Pick::CONFLICT:
+                conflicted = true;
+                [[fallthrough]];
+            case Pick::OURS:
+                merged->set_name(ours->name());
+                break;
+        }
+
+        std::set<std::string> keys;
+        for (auto const &entry : ours->metadata()) {
+            keys.insert(entry.first);
+        }
+        for (auto const &entry : theirs->metadata()) {
+            keys.insert(entry.first);
+        }
+        for (auto const &entry : base->metadata()) {
+            keys.insert(entry.first);
+        }
+        otio::AnyDictionary metadata = ours->metadata();
+        for (auto const &key : keys) {
+            auto choice =
+                    pick(!shim_same_metadata_value(base->metadata(), ours->metadata(), key),
+                         !shim_same_metadata_value(base->metadata(), theirs->metadata(), key),
+                         shim_same_metadata_value(ours->metadata(), theirs->metadata(), key));
+            if (choice == Pick::CONFLICT) {
+                conflicted = true;
+            } else if (choice == Pick::THEIRS) {
+                auto value = theirs->metadata().find(key);
+                if (value == theirs->metadata().end()) {
+                    metadata.erase(key);
+                } else {
+                    metadata[key] = value->second;
+                }
+            }
+        }
+        merged->metadata() = metadata;
+        return conflicted;
+    }
+
+    // Returns a new object merging three versions of the same schema, or
+    // null on failure. Where both sides conflict, ours wins.
+    Object *merge(Object *base, Object *ours, Object *theirs, std::string const &path) {
+        if (ours->is_equivalent_to(*base)) {
+            return clone_of(theirs, error_status);
+        }
+        if (theirs->is_equivalent_to(*base) || ours->is_equivalent_to(*theirs)) {
+            return clone_of(ours, error_status);
+        }
+
+        bool ours_same = false, theirs_same = false, sides_same = false;
+        if (!same_other(base, ours, ours_same) || !same_other(base, theirs, theirs_same) ||
+            !same_other(ours, theirs, sides_same)) {
+            return nullptr;
+        }
+        auto other = pick(!ours_same, !theirs_same, sides_same);
+        bool conflicted = other == Pick::CONFLICT;
+        otio::SerializableObject::Retainer<Object> merged(
+                clone_of(other == Pick::THEIRS ? theirs : ours, error_status));
+        if (!merged) {
+            return nullptr;
+        }
+        conflicted |= merge_named(base, ours, theirs, merged.value);
+
+        auto base_item = dynamic_cast<otio::Item *>(base);
+        auto ours_item = dynamic_cast<otio::Item *>(ours);
+        auto theirs_item = dynamic_cast<otio::Item *>(theirs);
+        if (base_item && ours_item && theirs_item) {
+            auto merged_item = dynamic_cast<otio::Item *>(merged.value);
+            switch (pick(base_item->source_range(),
+                         ours_item->source_range(),
+                         theirs_item->source_range())) {
+                case Pick::THEIRS:
+                    merged_item->set_source_range(theirs_item->source_range());
+                    break;
+                case Pick::CONFLICT:
+                    conflicted = true;
+                    [[fallthrough]];
+                case Pick::OURS:
+                    merged_item->set_source_range(ours_item->source_range());
+                    break;
+            }
+        }
+        if (conflicted) {
+            result.add(OTIO_MergeConflict_Kind_BOTH_MODIFIED, base, ours, theirs, path);
+        }
+
+        auto base_composition = dynamic_cast<otio::Composition *>(base);
+        auto ours_composition = dynamic_cast<otio::Composition *>(ours);
+        auto theirs_composition = dynamic_cast<otio::Composition *>(theirs);
+        if (base_composition && ours_composition && theirs_composition) {
+            auto merged_composition = dynamic_cast<otio::Composition *>(merged.value);
+            merged_composition->clear_children();
+            if (!merge_children(
+                        base_composition,
+                        ours_composition,
+                        theirs_composition,
+                        merged_composition,
+                        path)) {
+                return nullptr;
+            }
+        }
+        return merged.take_value();
+    }
+
+    bool merge_children(
+            otio::Composition *base,
+            otio::Composition *ours,
+            otio::Composition *theirs,
+            otio::Composition *merged,
+            std::string const &path) {
+        auto const &base_children = base->children();
+        auto const &ours_children = ours->children();
+        auto const &theirs_children = theirs->children();
+        auto ours_to_base = match_to_base(base, ours);
+        auto theirs_to_base = match_to_base(base, theirs);
+        std::vector<size_t> base_in_ours(base_children.size(), missing);
+        std::vector<size_t> base_in_theirs(base_children.size(), missing);
+        for (size_t index = 0; index < ours_to_base.size(); index++) {
+            if (ours_to_base[index] != missing) {
+                base_in_ours[ours_to_base[index]] = index;
+            }
+        }
+        for (size_t index = 0; index < theirs_to_base.size(); index++) {
+            if (theirs_to_base[index] != missing) {
+                base_in_theirs[theirs_to_base[index]] = index;
+            }
+        }
+
+        // Children removed on one side survive only if the other modified
+        // them, which is a conflict.
+        std::vector<bool> ours_kept(ours_children.size(), false);
+        std::vector<bool> theirs_kept(theirs_children.size(), false);
+        for (size_t index = 0; index < base_children.size(); index++) {
+            size_t in_ours = base_in_ours[index];
+            size_t in_theirs = base_in_theirs[index];
+            if ((in_ours == missing) == (in_theirs == missing)) {
+                continue;
+            }
+            auto base_child = base_children[index].value;
+            auto child_path = join_path(path, base_child->name());
+            if (in_ours != missing) {
+                auto ours_child = ours_children[in_ours].value;
+                if (!ours_child->is_equivalent_to(*base_child)) {
+                    result.add(
+                            OTIO_MergeConflict_Kind_MODIFIED_AND_REMOVED,
+                            base_child,
+                            ours_child,
+                            nullptr,
+                            child_path);
+                    ours_kept[in_ours] = true;
+                }
+            } else {
+                auto theirs_child = theirs_children[in_theirs].value;
+                if (!theirs_child->is_equivalent_to(*base_child)) {
+                    result.add(
+                            OTIO_MergeConflict_Kind_MODIFIED_AND_REMOVED,
+                            base_child,
+                            nullptr,
+                            theirs_child,
+                            child_path);
+                    theirs_kept[in_theirs] = true;
+                }
+            }
+        }
+
+        // Children both sides added under the same name are kept once.
+        std::map<std::string, std::vector<size_t>> ours_added;
+        for (size_t index = ours_children.size(); index-- > 0;) {
+            if (ours_to_base[index] == missing) {
+                ours_kept[index] = true;
+                auto child = ours_children[index].value;
+                ours_added[child->schema_name() + "\n" + child->name()].push_back(index);
+            }
+        }
+        for (size_t index = 0; index < theirs_children.size(); index++) {
+            if (theirs_to_base[index] != missing) {
+                continue;
+            }
+            auto child = theirs_children[index].value;
+            auto &candidates = ours_added[child->schema_name() + "\n" + child->name()];
+            if (candidates.empty()) {
+                theirs_kept[index] = true;
+                continue;
+            }
+            auto ours_child = ours_children[candidates.back()].value;
+            candidates.pop_back();
+            if (!ours_child->is_equivalent_to(*child)) {
+                result.add(
+                        OTIO_MergeConflict_Kind_BOTH_ADDED,
+                        nullptr,
+                        ours_child,
+                        child,
+                        join_path(path, child->name()));
+            }
+        }
+
+        std::vector<Token> ours_tokens;
+        for (size_t index = 0; index < ours_children.size(); index++) {
+            size_t in_base = ours_to_base[index];
+            if (in_base != missing && base_in_theirs[in_base] != missing) {
+                ours_tokens.push_back({Token::BASE, in_base});
+            } else if (ours_kept[index]) {
+                ours_tokens.push_back({Token::OURS, index});
+            }
+        }
+        std::vector<Token> theirs_tokens;
+        for (size_t index = 0; index < theirs_children.size(); index++) {
+            size_t in_base = theirs_to_base[index];
+            if (in_base != missing && base_in_ours[in_base] != missing) {
+                theirs_tokens.push_back({Token::BASE, in_base});
+            } else if (theirs_kept[index]) {
+                theirs_tokens.push_back({Token::THEIRS, index});
+            }
+        }
+
+        // The side that reordered the children it shares with base sets the
+        // order; the other side's children follow their predecessor there.
+        auto shared_order = [](std::vector<Token> const &tokens) {
+            std::vector<size_t> order;
+            for (auto const &token : tokens) {
+                if (token.side == Token::BASE) {
+                    order.push_back(token.index);
+                }
+            }
+            return order;
+        };
+        auto ours_order = shared_order(ours_tokens);
+        auto theirs_order = shared_order(theirs_tokens);
+        bool ours_reordered = !std::is_sorted(ours_order.begin(), ours_order.end());
+        bool theirs_reordered = !std::is_sorted(theirs_order.begin(), theirs_order.end());
+        if (ours_reordered && theirs_reordered && ours_order != theirs_order) {
+            result.add(OTIO_MergeConflict_Kind_OVERLAPPING_EDIT, base, ours, theirs, path);
+        }
+        bool theirs_lead = theirs_reordered && !ours_reordered;
+        auto tokens = theirs_lead ? theirs_tokens : ours_tokens;
+        auto const &others = theirs_lead ? ours_tokens : theirs_tokens;
+        bool track = dynamic_cast<otio::Track *>(base) != nullptr;
+        auto child_of = [&](Token const &token) -> otio::Composable * {
+            switch (token.side) {
+                case Token::BASE:
+                    return base_children[token.index].value;
+                case Token::OURS:
+                    return ours_children[token.index].value;
+                case Token::THEIRS:
+                    return theirs_children[token.index].value;
+            }
+            return nullptr;
+        };
+        for (size_t position = 0; position < others.size(); position++) {
+            auto const &token = others[position];
+            if (std::find(tokens.begin(), tokens.end(), token) != tokens.end()) {
+                continue;
+            }
+            auto insert_at = tokens.begin();
+            if (position > 0) {
+                insert_at = std::find(tokens.begin(), tokens.end(), others[position - 1]) + 1;
+            }
+            // Additions of the leading side at the same spot come first; on a
+            // track both sides then edited the same part of the cut.
+            auto own = [&](Token const &candidate) {
+                return candidate.side == (theirs_lead ? Token::THEIRS : Token::OURS);
+            };
+            bool overlapping = false;
+            while (insert_at != tokens.end() && own(*insert_at)) {
+                overlapping = true;
+                ++insert_at;
+            }
+            if (track && overlapping) {
+                auto lead_child = child_of(*(insert_at - 1));
+                auto other_child = child_of(token);
+                result.add(
+                        OTIO_MergeConflict_Kind_OVERLAPPING_EDIT,
+                        nullptr,
+                        theirs_lead ? other_child : lead_child,
+                        theirs_lead ? lead_child : other_child,
+                        join_path(path, other_child->name()));
+            }
+            tokens.insert(insert_at, token);
+        }
+
+        for (auto const &token : tokens) {
+            otio::SerializableObject::Retainer<otio::Composable> child;
+            if (token.side == Token::BASE) {
+                auto base_child = base_children[token.index].value;
+                child = dynamic_cast<otio::Composable *>(merge(
+                        base_child,
+                        ours_children[base_in_ours[token.index]].value,
+                        theirs_children[base_in_theirs[token.index]].value,
+                        join_path(path, base_child->name())));
+            } else {
+                child = clone_of(child_of(token), error_status);
+            }
+            if (!child || !merged->append_child(child.value, error_status)) {
+                return false;
+            }
+        }
+        return true;
+    }
+};
+
+MergeResult *finish(MergeResult *result, Object *merged) {
+    if (!merged) {
+        delete result;
+        return nullptr;
+    }
+    result->merged_object = merged;
+    return result;
+}
+
+MergeResult *merge_compositions(
+        otio::Composition *base,
+        otio::Composition *ours,
+        otio::Composition *theirs,
+        OTIOErrorStatus *error_status) {
+    auto result = new MergeResult;
+    Merger merger{*result, shim_cast<otio::ErrorStatus>(error_status)};
+    return finish(result, merger.merge(base, ours, theirs, base->name()));
+}
+
+} // namespace
+
+int MergeConflictVector_size(MergeConflictVector *self) { return int(self->conflicts.size()); }
+
+bool MergeConflictVector_empty(MergeConflictVector *self) { return self->conflicts.empty(); }
+
+MergeConflict MergeConflictVector_at(MergeConflictVector *self, int pos) {
+    return self->conflicts.at(size_t(pos));
+}
+
+void MergeConflictVector_destroy(MergeConflictVector *self) { delete self; }
+
+OTIOSerializableObject *MergeResult_merged_object(MergeResult *self) {
+    return shim_cast<OTIOSerializableObject>(self->merged_object.value);
+}
+
+bool MergeResult_has_conflicts(MergeResult *self) { return !self->conflicts.empty(); }
+
+MergeConflictVector *MergeResult_conflicts(MergeResult *self) {
+    return new MergeConflictVector{self->conflicts};
+}
+
+void MergeResult_destroy(MergeResult *self) { delete self; }
+
+MergeResult *merge_timelines(
+        Timeline *base, Timeline *ours, Timeline *theirs, OTIOErrorStatus *error_status) {
+    auto base_timeline = shim_cast<otio::Timeline>(base);
+    auto ours_timeline = shim_cast<otio::Timeline>(ours);
+    auto theirs_timeline = shim_cast<otio::Timeline>(theirs);
+    auto status = shim_cast<otio::ErrorStatus>(error_status);
+    auto result = new MergeResult;
+    Merger merger{*result, status};
+
+    otio::SerializableObject::Retainer<otio::Timeline> merged(clone_of(ours_timeline, status));
+    if (!merged) {
+        delete result;
+        return nullptr;
+    }
+    bool conflicted =
+            merger.merge_named(base_timeline, ours_timeline, theirs_timeline, merged.value);
+    switch (pick(base_timeline->global_start_time(),
+                 ours_timeline->global_start_time(),
+                 theirs_timeline->global_start_time())) {
+        case Pick::THEIRS:
+            merged->set_global_start_time(theirs_timeline->global_start_time());
+            break;
+        case Pick::CONFLICT:
+            conflicted = true;
+            break;
+        case Pick::OURS:
+            break;
+    }
+    if (conflicted) {
+        result->add(
+                OTIO_MergeConflict_Kind_BOTH_MODIFIED,
+                base_timeline,
+                ours_timeline,
+                theirs_timeline,
+                base_timeline->name());
+    }
+
+    auto base_stack = base_timeline->tracks();
+    auto tracks = dynamic_cast<otio::Stack *>(merger.merge(
+            base_stack, ours_timeline->tracks(), theirs_timeline->tracks(), base_stack->name()));
+    if (!tracks) {
+        delete result;
+        return nullptr;
+    }
+    merged->set_tracks(tracks);
+    return finish(result, merged.take_value());
+}
+
+MergeResult *
+merge_stacks(Stack *base, Stack *ours, Stack *theirs, OTIOErrorStatus *error_status) {
+    return merge_compositions(
+            shim_cast<otio::Stack>(base),
+            shim_cast<otio::Stack>(ours),
+            shim_cast<otio::Stack>(theirs),
+            error_status);
+}
+
+MergeResult *
+merge_tracks(Track *base, Track *ours, Track *theirs, OTIOErrorStatus *error_status) {
+    return merge_compositions(
+            shim_cast<otio::Track>(base),
+            shim_cast<otio::Track>(ours),
+            shim_cast<otio::Track>(theirs),
+            error_status);
+}
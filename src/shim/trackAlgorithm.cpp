@@ -0,0 +1,174 @@
+// Gap normalisation declared in trackAlgorithm.h.
+
+#include "shim.h"
+
+#include <opentimelineio/gap.h>
+#include <opentimelineio/track.h>
+#include <opentimelineio/transition.h>
+
+#include "copentimelineio/trackAlgorithm.h"
+
+#include <vector>
+
+namespace {
+
+struct Entry {
+    otio::Composable *child = nullptr;
+    otio::Gap *gap = nullptr;
+    bool annotated = false;
+    bool annotated_counted = false;
+    // Set when later gaps were merged into this one.
+    bool merged = false;
+    otio::TimeRange range;
+};
+
+bool is_transition(otio::Composable *child) {
+    return dynamic_cast<otio::Transition *>(child) != nullptr;
+}
+
+void count_annotated(Entry &entry, GapNormalizationReport &report) {
+    if (!entry.annotated_counted) {
+        entry.annotated_counted = true;
+        report.annotated_gaps_kept++;
+    }
+}
+
+} // namespace
+
+bool track_normalize_gaps(
+        Track *in_track,
+        bool strip_leading,
+        bool strip_trailing,
+        GapNormalizationReport *report,
+        OTIOErrorStatus *error_status) {
+    auto track = shim_cast<otio::Track>(in_track);
+    auto status = shim_cast<otio::ErrorStatus>(error_status);
+    GapNormalizationReport counts{};
+    if (report) {
+        *report = counts;
+    }
+
+    // Holding the children keeps them alive while the track is rebuilt.
+    std::vector<otio::SerializableObject::Retainer<otio::Composable>> original(
+            track->children().begin(), track->children().end());
+    std::vector<Entry> entries(original.size());
+    for (size_t index = 0; index < original.size(); index++) {
+        Entry &entry = entries[index];
+        entry.child = original[index].value;
+        entry.gap = dynamic_cast<otio::Gap *>(entry.child);
+        if (entry.gap) {
+            entry.annotated = !entry.gap->effects().empty() || !entry.gap->markers().empty();
+            entry.range = entry.gap->trimmed_range(status);
+            if (otio::is_error(status)) {
+                return false;
+            }
+        }
+    }
+
+    // Zero-length gaps go first, so that the gaps around them can merge.
+    std::vector<size_t> kept;
+    for (size_t index = 0; index < entries.size(); index++) {
+        Entry &entry = entries[index];
+        if (entry.gap && entry.range.duration().value() == 0) {
+            bool transition_neighbour =
+                    (index > 0 && is_transition(entries[index - 1].child)) ||
+                    (index + 1 < entries.size() && is_transition(entries[index + 1].child));
+            // A transition's neighbour is kept whatever it carries.
+            if (!transition_neighbour) {
+                if (entry.annotated) {
+                    count_annotated(entry, counts);
+                } else {
+                    counts.removed_zero_length_gaps++;
+                    continue;
+                }
+            }
+        }
+        kept.push_back(index);
+    }
+
+    std::vector<size_t> merged;
+    for (size_t index : kept) {
+        Entry &entry = entries[index];
+        if (entry.gap && !merged.empty() && entries[merged.back()].gap) {
+            Entry &previous = entries[merged.back()];
+            if (!entry.annotated && !previous.annotated) {
+                auto rate = previous.range.duration().rate();
+                previous.range = otio::TimeRange(
+                        previous.range.start_time(),
+                        previous.range.duration() + entry.range.duration().rescaled_to(rate));
+                previous.merged = true;
+                counts.merged_gaps++;
+                continue;
+            }
+            if (entry.annotated) {
+                count_annotated(entry, counts);
+            }
+            if (previous.annotated) {
+                count_annotated(previous, counts);
+            }
+        }
+        merged.push_back(index);
+    }
+
+    // A transition may not become the first or last child.
+    size_t begin = 0;
+    size_t end = merged.size();
+    while (strip_leading && begin < end && entries[merged[begin]].gap) {
+        Entry &entry = entries[merged[begin]];
+        if (begin + 1 < end && is_transition(entries[merged[begin + 1]].child)) {
+            break;
+        }
+        if (entry.annotated) {
+            count_annotated(entry, counts);
+            break;
+        }
+        counts.removed_leading_gaps++;
+        begin++;
+    }
+    while (strip_trailing && begin < end && entries[merged[end - 1]].gap) {
+        Entry &entry = entries[merged[end - 1]];
+        if (end - 1 > begin && is_transition(entries[merged[end - 2]].child)) {
+            break;
+        }
+        if (entry.annotated) {
+            count_annotated(entry, counts);
+            break;
+        }
+        counts.removed_trailing_gaps++;
+        end--;
+    }
+
+    if (end - begin != original.size()) {
+        std::vector<otio::Composable *> children;
+        for (size_t position = begin; position < end; position++) {
+            children.push_back(entries[merged[position]].child);
+        }
+        track->clear_children();
+        if (!track->set_children(children, status)) {
+            std::vector<otio::Composable *> restored;
+            for (auto const &child : original) {
+                restored.push_back(child.value);
+            }
+            track->clear_children();
+            otio::ErrorStatus restore_status;
+            if (!track->set_children(restored, &restore_status)) {
+                shim_set_error(
+                        error_status,
+                        restore_status.outcome,
+                        "could not restore the track after a failed normalization: " +
+                                restore_status.details);
+            }
+            return false;
+        }
+    }
+    for (size_t position = begin; position < end; position++) {
+        Entry const &entry = entries[merged[position]];
+        if (entry.merged) {
+            entry.gap->set_source_range(entry.range);
+        }
+    }
+    if (report) {
+        *report = counts;
+    }
+    return true;
+}
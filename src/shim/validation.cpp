@@ -0,0 +1,304 @@
+// Timeline lint pass declared in validation.h.
+
+#include "shim.h"
+
+#include <opentimelineio/anyDictionary.h>
+#include <opentimelineio/anyVector.h>
+#include <opentimelineio/clip.h>
+#include <opentimelineio/missingReference.h>
+#include <opentimelineio/stack.h>
+#include <opentimelineio/timeline.h>
+#include <opentimelineio/track.h>
+#include <opentimelineio/transition.h>
+#include <opentimelineio/unknownSchema.h>
+
+#include "copentimelineio/validation.h"
+
+#include <memory>
+#include <string>
+#include <vector>
+
+struct DiagnosticVector {
+    std::vector<Diagnostic> diagnostics;
+    // Owned here; each Diagnostic points into these.
+    std::vector<std::unique_ptr<std::string>> strings;
+
+    void add(
+            OTIO_Diagnostic_Severity severity,
+            OTIO_Diagnostic_Kind kind,
+            otio::SerializableObject *object,
+            std::string const &path,
+            std::string const &message) {
+        strings.push_back(std::make_unique<std::string>(path));
+        auto object_path = strings.back()->c_str();
+        strings.push_back(std::make_unique<std::string>(message));
+        Diagnostic diagnostic{};
+        diagnostic.severity = severity;
+        diagnostic.kind = kind;
+        diagnostic.object = shim_cast<OTIOSerializableObject>(object);
+        diagnostic.object_path = object_path;
+        diagnostic.message = strings.back()->c_str();
+        diagnostics.push_back(diagnostic);
+    }
+};
+
+namespace {
+
+std::string join_path(std::string const &parent_path, std::string const &name) {
+    return parent_path.empty() ? name : parent_path + "/" + name;
+}
+
+struct Validator {
+    DiagnosticVector &found;
+    otio::ErrorStatus *error_status;
+    // The rate of the first item visited; any other rate is reported.
+    otio::optional<double> rate;
+
+    void check_metadata_value(
+            otio::any const &value, otio::SerializableObject *owner, std::string const &path) {
+        if (auto retainer = otio::any_cast<otio::SerializableObject::Retainer<>>(&value)) {
+            if (auto unknown = dynamic_cast<otio::UnknownSchema *>(retainer->value)) {
+                found.add(
+                        OTIO_Diagnostic_Severity_WARNING,
+                        OTIO_Diagnostic_Kind_UNKNOWN_SCHEMA,
+                        unknown,
+                        path,
+                        "unknown schema " + unknown->original_schema_name());
+            }
+        } else if (auto dictionary = otio::any_cast<otio::AnyDictionary>(&value)) {
+            check_metadata(*dictionary, owner, path);
+        } else if (auto vector = otio::any_cast<otio::AnyVector>(&value)) {
+            for (size_t index = 0; index < vector->size(); index++) {
+                check_metadata_value(
+                        (*vector)[index], owner, join_path(path, std::to_string(index)));
+            }
+        }
+    }
+
+    void check_metadata(
+            otio::AnyDictionary const &metadata,
+            otio::SerializableObject *owner,
+            std::string const &path) {
+        for (auto const &entry : metadata) {
+            check_metadata_value(entry.second, owner, join_path(path, entry.first));
+        }
+    }
+
+    void check_duration(otio::Item *item, std::string const &path) {
+        auto source_range = item->source_range();
+        bool composition = dynamic_cast<otio::Composition *>(item) != nullptr;
+        if (composition && !source_range) {
+            return;
+        }
+        otio::ErrorStatus range_status;
+        auto range = source_range ? *source_range : item->trimmed_range(&range_status);
+        if (otio::is_error(&range_status)) {
+            return;
+        }
+        if (range.duration().value() < 0) {
+            found.add(
+                    OTIO_Diagnostic_Severity_ERROR,
+                    OTIO_Diagnostic_Kind_NEGATIVE_DURATION,
+                    item,
+                    path,
+                    "duration is negative");
+        } else if (range.duration().value() == 0) {
+            found.add(
+                    OTIO_Diagnostic_Severity_WARNING,
+                    OTIO_Diagnostic_Kind_ZERO_DURATION,
+                    item,
+                    path,
+                    "duration is zero");
+        }
+        if (composition) {
+            return;
+        }
+        double item_rate = range.duration().rate();
+        if (!rate) {
+            rate = item_rate;
+        } else if (*rate != item_rate) {
+            found.add(
+                    OTIO_Diagnostic_Severity_WARNING,
+                    OTIO_Diagnostic_Kind_MIXED_FRAME_RATES,
+                    item,
+                    path,
+                    "rate " + std::to_string(item_rate) + " differs from " +
+                            std::to_string(*rate));
+        }
+    }
+
+    void check_clip(otio::Clip *clip, std::string const &path) {
+        auto reference = clip->media_reference();
+        if (!reference || dynamic_cast<otio::MissingReference *>(reference)) {
+            found.add(
+                    OTIO_Diagnostic_Severity_WARNING,
+                    OTIO_Diagnostic_Kind_MISSING_MEDIA_REFERENCE,
+                    clip,
+                    path,
+                    "clip has no media reference");
+            return;
+        }
+        auto source_range = clip->source_range();
+        auto available_range = reference->available_range();
+        if (!source_range || !available_range) {
+            return;
+        }
+        if (source_range->start_time() < available_range->start_time() ||
+            available_range->end_time_exclusive() < source_range->end_time_exclusive()) {
+            found.add(
+                    OTIO_Diagnostic_Severity_WARNING,
+                    OTIO_Diagnostic_Kind_SOURCE_RANGE_OUTSIDE_AVAILABLE_RANGE,
+                    clip,
+                    path,
+                    "source range is outside the media's available range");
+        }
+    }
+
+    // Checks the transitions of track against their position and the media
+    // their neighbours have to spare.
+    bool check_transitions(otio::Track *track, std::vector<std::string> const &paths) {
+        auto const &children = track->children();
+        for (size_t index = 0; index < children.size(); index++) {
+            auto transition = dynamic_cast<otio::Transition *>(children[index].value);
+            if (!transition) {
+                continue;
+            }
+            if (index == 0 || index + 1 == children.size()) {
+                found.add(
+                        OTIO_Diagnostic_Severity_ERROR,
+                        OTIO_Diagnostic_Kind_TRANSITION_AT_TRACK_EDGE,
+                        transition,
+                        paths[index],
+                        "transition has no neighbour on one side");
+            }
+            if (index > 0 && dynamic_cast<otio::Transition *>(children[index - 1].value)) {
+                found.add(
+                        OTIO_Diagnostic_Severity_ERROR,
+                        OTIO_Diagnostic_Kind_ADJACENT_TRANSITIONS,
+                        transition,
+                        paths[index],
+                        "transition follows another transition");
+            }
+        }
+        for (size_t index = 0; index < children.size(); index++) {
+            auto clip = dynamic_cast<otio::Clip *>(children[index].value);
+            if (!clip) {
+                continue;
+            }
+            auto handles = track->handles_of_child(clip, error_status);
+            if (otio::is_error(error_status)) {
+                return false;
+            }
+            if (!handles.first && !handles.second) {
+                continue;
+            }
+            otio::ErrorStatus range_status;
+            auto trimmed_range = clip->trimmed_range(&range_status);
+            auto available_range = clip->available_range(&range_status);
+            if (otio::is_error(&range_status)) {
+                continue;
+            }
+            if (handles.first &&
+                trimmed_range.start_time() - *handles.first < available_range.start_time()) {
+                found.add(
+                        OTIO_Diagnostic_Severity_ERROR,
+                        OTIO_Diagnostic_Kind_TRANSITION_EXCEEDS_HANDLES,
+                        children[index - 1].value,
+                        paths[index - 1],
+                        "transition needs more media before " + clip->name() + " than it has");
+            }
+            if (handles.second &&
+                available_range.end_time_exclusive() <
+                        trimmed_range.end_time_exclusive() + *handles.second) {
+                found.add(
+                        OTIO_Diagnostic_Severity_ERROR,
+                        OTIO_Diagnostic_Kind_TRANSITION_EXCEEDS_HANDLES,
+                        children[index + 1].value,
+                        paths[index + 1],
+                        "transition needs more media after " + clip->name() + " than it has");
+            }
+        }
+        return true;
+    }
+
+    bool visit(otio::Composable *composable, std::string const &path) {
+        check_metadata(composable->metadata(), composable, path);
+        auto item = dynamic_cast<otio::Item *>(composable);
+        if (!item) {
+            return true;
+        }
+        check_duration(item, path);
+        if (auto clip = dynamic_cast<otio::Clip *>(item)) {
+            check_clip(clip, path);
+        }
+        auto composition = dynamic_cast<otio::Composition *>(item);
+        if (!composition) {
+            return true;
+        }
+        std::vector<std::string> paths;
+        for (auto const &child : composition->children()) {
+            paths.push_back(join_path(path, child.value->name()));
+        }
+        if (auto track = dynamic_cast<otio::Track *>(composition)) {
+            if (!check_transitions(track, paths)) {
+                return false;
+            }
+        }
+        auto const &children = composition->children();
+        for (size_t index = 0; index < children.size(); index++) {
+            if (!visit(children[index].value, paths[index])) {
+                return false;
+            }
+        }
+        return true;
+    }
+};
+
+} // namespace
+
+int DiagnosticVector_size(DiagnosticVector *self) { return int(self->diagnostics.size()); }
+
+bool DiagnosticVector_empty(DiagnosticVector *self) { return self->diagnostics.empty(); }
+
+Diagnostic DiagnosticVector_at(DiagnosticVector *self, int pos) {
+    return self->diagnostics.at(size_t(pos));
+}
+
+void DiagnosticVector_destroy(DiagnosticVector *self) { delete self; }
+
+const char *Diagnostic_kind_to_string(OTIO_Diagnostic_Kind kind) {
+    switch (kind) {
+        case OTIO_Diagnostic_Kind_TRANSITION_AT_TRACK_EDGE:
+            return "TRANSITION_AT_TRACK_EDGE";
+        case OTIO_Diagnostic_Kind_ADJACENT_TRANSITIONS:
+            return "ADJACENT_TRANSITIONS";
+        case OTIO_Diagnostic_Kind_TRANSITION_EXCEEDS_HANDLES:
+            return "TRANSITION_EXCEEDS_HANDLES";
+        case OTIO_Diagnostic_Kind_NEGATIVE_DURATION:
+            return "NEGATIVE_DURATION";
+        case OTIO_Diagnostic_Kind_ZERO_DURATION:
+            return "ZERO_DURATION";
+        case OTIO_Diagnostic_Kind_SOURCE_RANGE_OUTSIDE_AVAILABLE_RANGE:
+            return "SOURCE_RANGE_OUTSIDE_AVAILABLE_RANGE";
+        case OTIO_Diagnostic_Kind_MIXED_FRAME_RATES:
+            return "MIXED_FRAME_RATES";
+        case OTIO_Diagnostic_Kind_MISSING_MEDIA_REFERENCE:
+            return "MISSING_MEDIA_REFERENCE";
+        case OTIO_Diagnostic_Kind_UNKNOWN_SCHEMA:
+            return "UNKNOWN_SCHEMA";
+    }
+    return "";
+}
+
+DiagnosticVector *validate_timeline(Timeline *in_timeline, OTIOErrorStatus *error_status) {
+    auto timeline = shim_cast<otio::Timeline>(in_timeline);
+    auto result = new DiagnosticVector;
+    Validator validator{*result, shim_cast<otio::ErrorStatus>(error_status), {}};
+    validator.check_metadata(timeline->metadata(), timeline, timeline->name());
+    auto stack = timeline->tracks();
+    if (!validator.visit(stack, stack->name())) {
+        delete result;
+        return nullptr;
+    }
+    return result;
+}
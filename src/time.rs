@@ -0,0 +1,390 @@
+//! Frame iteration, ordering and range sets for the opentime value types.
+
+use crate::bindings;
+use crate::bindings::*;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::num::NonZeroU64;
+use std::ptr;
+
+/// Iterator over the whole frames at a rate that start within a range.
+///
+/// Yields exact `RationalTime`s at the iteration rate, from either end.
+pub struct Frames {
+    iter: *mut TimeRangeFrameIterator,
+}
+
+impl Frames {
+    fn new(range: TimeRange, rate: f64, step: NonZeroU64) -> Self {
+        let step = i64::try_from(step.get()).unwrap_or(i64::MAX);
+        let iter = unsafe { TimeRange_frames_step_by(range, rate, step, false, ptr::null_mut()) };
+        Frames { iter }
+    }
+
+    fn take(
+        &mut self,
+        next: unsafe extern "C" fn(*mut TimeRangeFrameIterator, *mut RationalTime) -> bool,
+    ) -> Option<RationalTime> {
+        if self.iter.is_null() {
+            return None;
+        }
+        let mut frame_time = RationalTime {
+            value: 0.0,
+            rate: 0.0,
+        };
+        unsafe { next(self.iter, &mut frame_time) }.then_some(frame_time)
+    }
+}
+
+impl Iterator for Frames {
+    type Item = RationalTime;
+
+    fn next(&mut self) -> Option<RationalTime> {
+        self.take(TimeRangeFrameIterator_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.iter.is_null() {
+            0
+        } else {
+            unsafe { TimeRangeFrameIterator_remaining(self.iter) }
+        };
+        let remaining = usize::try_from(remaining).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Frames {
+    fn next_back(&mut self) -> Option<RationalTime> {
+        self.take(TimeRangeFrameIterator_next_back)
+    }
+}
+
+impl ExactSizeIterator for Frames {}
+
+impl FusedIterator for Frames {}
+
+impl Drop for Frames {
+    fn drop(&mut self) {
+        if !self.iter.is_null() {
+            unsafe { TimeRangeFrameIterator_destroy(self.iter) }
+        }
+    }
+}
+
+impl TimeRange {
+    /// Iterates the frames at `rate` that start within this range; a rate
+    /// that is not positive yields nothing.
+    pub fn frames(&self, rate: f64) -> Frames {
+        Frames::new(*self, rate, NonZeroU64::MIN)
+    }
+
+    /// Iterates every `step`-th frame at `rate`, starting from the first.
+    pub fn frames_step_by(&self, rate: f64, step: NonZeroU64) -> Frames {
+        Frames::new(*self, rate, step)
+    }
+}
+
+/// An owned set of disjoint time ranges, built on `TimeRangeSet`.
+///
+/// Touching ranges are merged. The set takes the rate of the first range
+/// inserted; ranges with a rate that is not positive are ignored.
+pub struct TimeRangeSet {
+    set: *mut bindings::TimeRangeSet,
+}
+
+impl TimeRangeSet {
+    pub fn new() -> Self {
+        TimeRangeSet {
+            set: unsafe { TimeRangeSet_create() },
+        }
+    }
+
+    fn from_raw(set: *mut bindings::TimeRangeSet) -> Self {
+        TimeRangeSet { set }
+    }
+
+    pub fn insert(&mut self, range: TimeRange) {
+        unsafe { TimeRangeSet_insert(self.set, range) }
+    }
+
+    pub fn remove(&mut self, range: TimeRange) {
+        unsafe { TimeRangeSet_remove(self.set, range) }
+    }
+
+    pub fn clear(&mut self) {
+        unsafe { TimeRangeSet_clear(self.set) }
+    }
+
+    /// The number of disjoint ranges.
+    pub fn len(&self) -> usize {
+        usize::try_from(unsafe { TimeRangeSet_size(self.set) }).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe { TimeRangeSet_empty(self.set) }
+    }
+
+    /// The ranges in start order, at the set's rate.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = TimeRange> + '_ {
+        (0..self.len()).map(move |index| unsafe { TimeRangeSet_at(self.set, index as i32) })
+    }
+
+    /// The range from the start of the first range to the end of the last.
+    pub fn bounds(&self) -> Option<TimeRange> {
+        unsafe { TimeRangeSet_bounds(self.set) }.into()
+    }
+
+    /// The total duration of the ranges.
+    pub fn coverage(&self) -> RationalTime {
+        unsafe { TimeRangeSet_coverage(self.set) }
+    }
+
+    pub fn contains_time(&self, time: RationalTime) -> bool {
+        unsafe { TimeRangeSet_contains_rational_time(self.set, time) }
+    }
+
+    pub fn contains_range(&self, range: TimeRange) -> bool {
+        unsafe { TimeRangeSet_contains_time_range(self.set, range) }
+    }
+
+    pub fn overlaps(&self, range: TimeRange) -> bool {
+        unsafe { TimeRangeSet_overlaps_time_range(self.set, range) }
+    }
+
+    /// The results below are at this set's rate, or `other`'s if this set
+    /// is empty.
+    pub fn union(&self, other: &TimeRangeSet) -> TimeRangeSet {
+        Self::from_raw(unsafe { TimeRangeSet_union(self.set, other.set) })
+    }
+
+    pub fn intersection(&self, other: &TimeRangeSet) -> TimeRangeSet {
+        Self::from_raw(unsafe { TimeRangeSet_intersection(self.set, other.set) })
+    }
+
+    pub fn difference(&self, other: &TimeRangeSet) -> TimeRangeSet {
+        Self::from_raw(unsafe { TimeRangeSet_difference(self.set, other.set) })
+    }
+
+    /// The parts of `bounds` not in the set, at the rate of `bounds`.
+    pub fn complement_within(&self, bounds: TimeRange) -> TimeRangeSet {
+        Self::from_raw(unsafe { TimeRangeSet_complement_within(self.set, bounds) })
+    }
+}
+
+impl Default for TimeRangeSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for TimeRangeSet {
+    fn clone(&self) -> Self {
+        Self::from_raw(unsafe { TimeRangeSet_clone(self.set) })
+    }
+}
+
+impl PartialEq for TimeRangeSet {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { TimeRangeSet_equal(self.set, other.set) }
+    }
+}
+
+impl fmt::Debug for TimeRangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<TimeRange> for TimeRangeSet {
+    fn extend<I: IntoIterator<Item = TimeRange>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl FromIterator<TimeRange> for TimeRangeSet {
+    fn from_iter<I: IntoIterator<Item = TimeRange>>(ranges: I) -> Self {
+        let mut set = TimeRangeSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl Drop for TimeRangeSet {
+    fn drop(&mut self) {
+        unsafe { TimeRangeSet_destroy(self.set) }
+    }
+}
+
+/// A valid `RationalTime` that compares, orders and hashes by seconds, so
+/// that times can be sorted and used as `BTreeMap` or `HashMap` keys. 1 at 24
+/// equals 2 at 48.
+///
+/// Invalid times (see `RationalTime_is_invalid_time`), including any with a
+/// NaN value or rate, are rejected by `new`, which keeps the order total.
+#[derive(Debug, Copy, Clone)]
+pub struct TimeKey(RationalTime);
+
+impl TimeKey {
+    /// Returns `None` when `time` is invalid, or when its seconds are NaN
+    /// (an infinite value at an infinite rate).
+    pub fn new(time: RationalTime) -> Option<Self> {
+        let valid = time.rate > 0.0 && !(time.value / time.rate).is_nan();
+        valid.then_some(TimeKey(time))
+    }
+
+    pub fn time(self) -> RationalTime {
+        self.0
+    }
+}
+
+impl From<TimeKey> for RationalTime {
+    fn from(key: TimeKey) -> Self {
+        key.0
+    }
+}
+
+impl PartialEq for TimeKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TimeKey {}
+
+impl Ord for TimeKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe { RationalTime_cmp(self.0, other.0) }.cmp(&0)
+    }
+}
+
+impl PartialOrd for TimeKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for TimeKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(unsafe { RationalTime_hash(self.0) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: f64, duration: f64, rate: f64) -> TimeRange {
+        TimeRange {
+            start_time: RationalTime { value: start, rate },
+            duration: RationalTime {
+                value: duration,
+                rate,
+            },
+        }
+    }
+
+    fn values(frames: impl Iterator<Item = RationalTime>) -> Vec<f64> {
+        frames.map(|time| time.value).collect()
+    }
+
+    #[test]
+    fn frames_iterate_from_both_ends() {
+        let frames = range(1.0, 4.0, 24.0).frames(24.0);
+        assert_eq!(frames.len(), 4);
+        assert_eq!(values(frames), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            values(range(1.0, 4.0, 24.0).frames(24.0).rev()),
+            [4.0, 3.0, 2.0, 1.0]
+        );
+
+        let mut frames = range(0.0, 3.0, 24.0).frames(24.0);
+        assert_eq!(frames.next().map(|time| time.value), Some(0.0));
+        assert_eq!(frames.next_back().map(|time| time.value), Some(2.0));
+        assert_eq!(frames.next().map(|time| time.value), Some(1.0));
+        assert!(frames.next_back().is_none());
+    }
+
+    #[test]
+    fn frames_rescale_and_step() {
+        // 0.5..7 at 24 is 1..14 at 48.
+        let frames = range(0.5, 6.5, 24.0).frames(48.0);
+        assert_eq!(values(frames), (1..14).map(f64::from).collect::<Vec<_>>());
+        let step = NonZeroU64::new(5).unwrap();
+        let stepped = range(0.0, 12.0, 24.0).frames_step_by(24.0, step);
+        assert_eq!(values(stepped.rev()), [10.0, 5.0, 0.0]);
+        assert_eq!(range(0.0, 12.0, 24.0).frames(0.0).count(), 0);
+    }
+
+    fn time(value: f64, rate: f64) -> RationalTime {
+        RationalTime { value, rate }
+    }
+
+    fn key(value: f64, rate: f64) -> TimeKey {
+        TimeKey::new(time(value, rate)).unwrap()
+    }
+
+    #[test]
+    fn times_order_across_rates() {
+        assert_eq!(key(1.0, 24.0), key(2.0, 48.0));
+        assert!(key(1.0, 24.0) < key(3.0, 48.0));
+        assert_eq!(key(0.0, 24.0), key(-0.0, 24.0));
+        assert!(key(f64::INFINITY, 24.0) > key(1e300, 1.0));
+        assert!(TimeKey::new(time(f64::NAN, 24.0)).is_none());
+        assert!(TimeKey::new(time(1.0, f64::NAN)).is_none());
+        assert!(TimeKey::new(time(1.0, 0.0)).is_none());
+        assert!(TimeKey::new(time(f64::INFINITY, f64::INFINITY)).is_none());
+
+        let mut keys = std::collections::BTreeMap::new();
+        keys.insert(key(2.0, 48.0), "a");
+        keys.insert(key(1.0, 24.0), "b");
+        keys.insert(key(0.0, 24.0), "c");
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[&key(4.0, 96.0)], "b");
+        assert_eq!(keys.keys().next().unwrap().time().value, 0.0);
+    }
+
+    #[test]
+    fn range_sets_are_owned_and_ignore_bad_rates() {
+        let mut set: TimeRangeSet = [range(0.0, 10.0, 24.0), range(20.0, 10.0, 24.0)]
+            .into_iter()
+            .collect();
+        set.insert(range(5.0, 5.0, 0.0));
+        set.insert(range(10.0, 5.0, 24.0));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains_range(range(2.0, 12.0, 24.0)));
+        assert!(!set.contains_time(time(17.0, 24.0)));
+        assert!(!set.contains_time(time(1.0, 0.0)));
+        assert!(set.overlaps(range(14.0, 7.0, 24.0)));
+
+        let gaps = set.complement_within(range(0.0, 40.0, 24.0));
+        assert_eq!(
+            gaps.iter()
+                .map(|range| (range.start_time.value, range.duration.value))
+                .collect::<Vec<_>>(),
+            [(15.0, 5.0), (30.0, 10.0)]
+        );
+        assert_eq!(set.union(&gaps).coverage().value, 40.0);
+        assert!(set.intersection(&gaps).is_empty());
+        assert_eq!(set.clone(), set);
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn equal_times_hash_alike() {
+        let hashes: std::collections::HashSet<_> =
+            [key(1.0, 24.0), key(2.0, 48.0), key(1001.0, 24024.0)]
+                .into_iter()
+                .collect();
+        assert_eq!(hashes.len(), 1);
+        assert_eq!(
+            unsafe { RationalTime_cmp(time(1.0, 24.0), time(1.0, 25.0)) },
+            1
+        );
+    }
+}
@@ -0,0 +1,390 @@
+//! Checks of the opentime part of the shim, which `build.rs` compiles even
+//! without OpenTimelineIO. Error statuses can only be created by the native
+//! library, so these pass null and look at the returned values; the smoke
+//! tests cover error reporting.
+
+use opentimelineio_bind::*;
+use std::ptr;
+
+fn rational(numerator: i64, denominator: i64) -> ExactRational {
+    unsafe { ExactRational_create(numerator, denominator, ptr::null_mut()) }
+}
+
+fn frames(frames: i64, rate: ExactRational) -> ExactRationalTime {
+    unsafe { ExactRationalTime_from_frames(frames, rate) }
+}
+
+#[test]
+fn rationals_are_reduced() {
+    let half = rational(-3, -6);
+    assert_eq!((half.numerator, half.denominator), (1, 2));
+    let negative = rational(2, -4);
+    assert_eq!((negative.numerator, negative.denominator), (-1, 2));
+    assert_eq!(rational(1, 0).denominator, 0);
+}
+
+#[test]
+fn float_rates_snap_to_ntsc() {
+    unsafe {
+        for (rate, numerator) in [(23.976, 24000), (29.97, 30000), (59.94, 60000)] {
+            let exact = ExactRational_from_rate(rate);
+            assert_eq!((exact.numerator, exact.denominator), (numerator, 1001));
+            assert!(ExactRational_is_ntsc_rate(exact));
+        }
+        let film = ExactRational_from_rate(24.0);
+        assert_eq!((film.numerator, film.denominator), (24, 1));
+        assert!(!ExactRational_is_ntsc_rate(film));
+        let half = ExactRational_from_rate(12.5);
+        assert_eq!((half.numerator, half.denominator), (25, 2));
+        // Close to 1000/1001, but not a standard NTSC rate.
+        let slow = ExactRational_from_rate(0.999);
+        assert_eq!((slow.numerator, slow.denominator), (999, 1000));
+        assert!(!ExactRational_is_ntsc_rate(slow));
+    }
+}
+
+#[test]
+fn ntsc_arithmetic_does_not_drift() {
+    unsafe {
+        let ntsc = rational(24000, 1001);
+        let mut time = frames(0, ntsc);
+        let one = frames(1, ntsc);
+        for _ in 0..24 * 60 * 60 * 3 {
+            time = ExactRationalTime_add(time, one, ptr::null_mut());
+        }
+        assert!(ExactRationalTime_equal(
+            time,
+            frames(24 * 60 * 60 * 3, ntsc)
+        ));
+        let seconds = ExactRationalTime_to_seconds(time);
+        assert_eq!((seconds.numerator, seconds.denominator), (54054, 5));
+    }
+}
+
+#[test]
+fn mixed_rates_add_at_the_higher_rate() {
+    unsafe {
+        let sum = ExactRationalTime_add(
+            frames(24, rational(24, 1)),
+            frames(12, rational(48, 1)),
+            ptr::null_mut(),
+        );
+        assert_eq!(sum.rate.numerator, 48);
+        assert_eq!((sum.value.numerator, sum.value.denominator), (60, 1));
+        let difference = ExactRationalTime_subtract(
+            frames(24, rational(24, 1)),
+            frames(12, rational(48, 1)),
+            ptr::null_mut(),
+        );
+        assert_eq!(difference.value.numerator, 36);
+    }
+}
+
+#[test]
+fn overflow_gives_an_invalid_time() {
+    unsafe {
+        let sum = ExactRationalTime_add(
+            frames(i64::MAX, rational(1, 1)),
+            frames(1, rational(1, 1)),
+            ptr::null_mut(),
+        );
+        assert!(ExactRationalTime_is_invalid_time(sum));
+    }
+}
+
+#[test]
+fn comparison_is_exact() {
+    unsafe {
+        let ntsc = rational(30000, 1001);
+        let a = frames(1001, ntsc);
+        let b = frames(1000, rational(30, 1));
+        assert_eq!(
+            ExactRationalTime_compare(a, ExactRationalTime_rescaled_to(a, rational(30, 1))),
+            0
+        );
+        assert_eq!(ExactRationalTime_compare(b, a), -1);
+        assert_eq!(ExactRationalTime_compare(a, b), 1);
+        assert!(ExactRationalTime_not_equal(a, b));
+    }
+}
+
+#[test]
+fn rational_times_convert_losslessly() {
+    unsafe {
+        let time = RationalTime {
+            value: 1.5,
+            rate: 29.97,
+        };
+        let exact = ExactRationalTime_from_rational_time(time, ptr::null_mut());
+        assert_eq!((exact.value.numerator, exact.value.denominator), (3, 2));
+        assert_eq!(exact.rate.denominator, 1001);
+        let back = ExactRationalTime_to_rational_time(exact);
+        assert_eq!(back.value, 1.5);
+        let invalid = ExactRationalTime_from_rational_time(
+            RationalTime {
+                value: f64::NAN,
+                rate: 24.0,
+            },
+            ptr::null_mut(),
+        );
+        assert!(ExactRationalTime_is_invalid_time(invalid));
+    }
+}
+
+fn time(value: f64, rate: f64) -> RationalTime {
+    RationalTime { value, rate }
+}
+
+#[test]
+fn frames_round_by_policy() {
+    unsafe {
+        let half = time(2.5, 24.0);
+        for (policy, frames) in [
+            (OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Floor, 2),
+            (OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Ceil, 3),
+            (OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Nearest, 3),
+            (
+                OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_NearestEven,
+                2,
+            ),
+        ] {
+            assert_eq!(RationalTime_to_frames_rounded(half, policy as i32), frames);
+        }
+        let ceil = OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Ceil as i32;
+        // 25 frames at 25 is 24 frames at 24, up to floating point noise.
+        assert_eq!(
+            RationalTime_to_frames_rounded_with_rate(time(25.0, 25.0), 24.0, ceil),
+            24
+        );
+        let samples = time(3.0 * 3600.0 * 48000.0, 48000.0);
+        assert_eq!(RationalTime_to_frames_rounded(samples, ceil), 518_400_000);
+        assert_eq!(
+            RationalTime_to_frames_rounded(time(1e30, 24.0), ceil),
+            i64::MAX
+        );
+    }
+}
+
+#[test]
+fn rescaling_and_snapping_round() {
+    unsafe {
+        let floor = OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Floor as i32;
+        let ceil = OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Ceil as i32;
+        let rescaled = RationalTime_rescaled_to_rounded(time(10.0, 24.0), 25.0, floor);
+        assert_eq!((rescaled.value, rescaled.rate), (10.0, 25.0));
+        let snapped = RationalTime_snap_to_frame(time(10.2, 24.0), ceil);
+        assert_eq!((snapped.value, snapped.rate), (11.0, 24.0));
+
+        let range = TimeRange {
+            start_time: time(1.5, 24.0),
+            duration: time(5.0, 48.0),
+        };
+        let snapped = TimeRange_snap_to_frame(range, floor, ceil);
+        assert_eq!(snapped.start_time.value, 1.0);
+        assert_eq!(snapped.duration.value, 3.0);
+        assert_eq!(snapped.duration.rate, 24.0);
+    }
+}
+
+unsafe fn timecode_string(timecode: Timecode) -> String {
+    let string = Timecode_to_string(timecode);
+    let result = std::ffi::CStr::from_ptr(string)
+        .to_str()
+        .unwrap()
+        .to_owned();
+    free(string as *mut std::os::raw::c_void);
+    result
+}
+
+extern "C" {
+    fn free(ptr: *mut std::os::raw::c_void);
+}
+
+const INFER: OpenTime_IsDropFrameRate =
+    OpenTime_IsDropFrameRate__OpenTime_IsDropFrameRate_InferFromRate;
+
+#[test]
+fn drop_frame_timecodes_skip_frame_numbers() {
+    unsafe {
+        let timecode = Timecode_from_frames(1800, 29.97, INFER, ptr::null_mut());
+        assert!(timecode.drop_frame);
+        assert_eq!(timecode_string(timecode), "00:01:00;02");
+        assert_eq!(Timecode_to_frames(timecode), 1800);
+        let ten_minutes = Timecode_from_frames(17982, 29.97, INFER, ptr::null_mut());
+        assert_eq!(timecode_string(ten_minutes), "00:10:00;00");
+
+        let text = std::ffi::CString::new("01:00:00;00").unwrap();
+        let parsed = Timecode_from_string(text.as_ptr(), 29.97, ptr::null_mut());
+        assert_eq!(Timecode_to_frames(parsed), 107_892);
+        let skipped = Timecode_create(0, 1, 0, 0, 29.97, INFER, ptr::null_mut());
+        assert_eq!(skipped.rate, 0.0);
+    }
+}
+
+#[test]
+fn timecode_arithmetic_wraps_and_goes_negative() {
+    unsafe {
+        let no = OpenTime_IsDropFrameRate__OpenTime_IsDropFrameRate_ForceNo;
+        let start = Timecode_create(23, 59, 59, 23, 24.0, no, ptr::null_mut());
+        let next = Timecode_add_frames(start, 1, ptr::null_mut());
+        assert_eq!(timecode_string(next), "24:00:00:00");
+        assert_eq!(
+            timecode_string(Timecode_wrapped_to_24_hours(next)),
+            "00:00:00:00"
+        );
+
+        let one = Timecode_from_frames(1, 24.0, no, ptr::null_mut());
+        let two = Timecode_from_frames(2, 24.0, no, ptr::null_mut());
+        let difference = Timecode_subtract(one, two, ptr::null_mut());
+        assert_eq!(timecode_string(difference), "-00:00:00:01");
+        assert_eq!(
+            timecode_string(Timecode_wrapped_to_24_hours(difference)),
+            "23:59:59:23"
+        );
+        assert_eq!(Timecode_compare(difference, one), -1);
+
+        let other_rate = Timecode_from_frames(2, 48.0, no, ptr::null_mut());
+        assert!(Timecode_equal(one, other_rate));
+        let mismatch = Timecode_add(one, other_rate, ptr::null_mut());
+        assert_eq!(mismatch.rate, 0.0);
+
+        let overflow = Timecode_add_frames(one, i64::MAX, ptr::null_mut());
+        assert_eq!(overflow.rate, 0.0);
+        // i64::MAX frames at one frame per second is far more hours than an
+        // int holds.
+        let too_long = Timecode_from_frames(i64::MAX, 1.0, no, ptr::null_mut());
+        assert_eq!(too_long.rate, 0.0);
+    }
+}
+
+#[test]
+fn timecodes_convert_to_rational_time() {
+    unsafe {
+        let time = time(48.0, 48.0);
+        let timecode = Timecode_from_rational_time(time, 24.0, INFER, ptr::null_mut());
+        assert_eq!(timecode_string(timecode), "00:00:01:00");
+        let back = Timecode_to_rational_time(timecode);
+        assert_eq!((back.value, back.rate), (24.0, 24.0));
+    }
+}
+
+#[test]
+fn feet_frames_round_trip() {
+    unsafe {
+        let four_perf = OpenTime_FilmGauge__OpenTime_FilmGauge_35mm_4Perf as i32;
+        let sixteen = OpenTime_FilmGauge__OpenTime_FilmGauge_16mm as i32;
+        let text = std::ffi::CString::new("12+08").unwrap();
+        let time = RationalTime_from_feet_frames(text.as_ptr(), 24.0, four_perf, ptr::null_mut());
+        assert_eq!((time.value, time.rate), (200.0, 24.0));
+        let string = RationalTime_to_feet_frames(time, sixteen);
+        assert_eq!(std::ffi::CStr::from_ptr(string).to_str(), Ok("5+00"));
+        free(string as *mut std::os::raw::c_void);
+
+        let too_many = std::ffi::CString::new("12+16").unwrap();
+        let invalid =
+            RationalTime_from_feet_frames(too_many.as_ptr(), 24.0, four_perf, ptr::null_mut());
+        assert_eq!(invalid.rate, 0.0);
+    }
+}
+
+#[test]
+fn sample_counts_convert() {
+    unsafe {
+        let nearest = OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Nearest as i32;
+        let samples = RationalTime_to_samples(time(1.0, 24.0), 48000.0, nearest);
+        assert_eq!(samples, 2000);
+        let time = RationalTime_from_samples(96_000_000_000, 48000.0);
+        assert_eq!((time.value, time.rate), (96e9, 48000.0));
+    }
+}
+
+fn range24(start: f64, duration: f64) -> TimeRange {
+    TimeRange {
+        start_time: time(start, 24.0),
+        duration: time(duration, 24.0),
+    }
+}
+
+unsafe fn ranges(set: *mut TimeRangeSet) -> Vec<(f64, f64)> {
+    (0..TimeRangeSet_size(set))
+        .map(|index| {
+            let range = TimeRangeSet_at(set, index);
+            (range.start_time.value, range.duration.value)
+        })
+        .collect()
+}
+
+#[test]
+fn range_sets_merge_and_split() {
+    unsafe {
+        let set = TimeRangeSet_create();
+        TimeRangeSet_insert(set, range24(0.0, 10.0));
+        TimeRangeSet_insert(set, range24(20.0, 10.0));
+        TimeRangeSet_insert(set, range24(10.0, 5.0));
+        assert_eq!(ranges(set), [(0.0, 15.0), (20.0, 10.0)]);
+        // Rescaled to the set's rate.
+        TimeRangeSet_insert(
+            set,
+            TimeRange {
+                start_time: time(60.0, 48.0),
+                duration: time(20.0, 48.0),
+            },
+        );
+        assert_eq!(ranges(set), [(0.0, 15.0), (20.0, 20.0)]);
+        TimeRangeSet_remove(set, range24(5.0, 20.0));
+        assert_eq!(ranges(set), [(0.0, 5.0), (25.0, 15.0)]);
+        assert_eq!(TimeRangeSet_coverage(set).value, 20.0);
+        assert!(TimeRangeSet_contains_rational_time(set, time(4.0, 24.0)));
+        assert!(!TimeRangeSet_contains_rational_time(set, time(5.0, 24.0)));
+        assert!(TimeRangeSet_overlaps_time_range(set, range24(4.0, 30.0)));
+        assert!(!TimeRangeSet_contains_time_range(set, range24(4.0, 30.0)));
+        let bounds = TimeRangeSet_bounds(set);
+        assert!(bounds.valid);
+        assert_eq!(bounds.value.duration.value, 40.0);
+        TimeRangeSet_destroy(set);
+    }
+}
+
+#[test]
+fn range_set_algebra() {
+    unsafe {
+        let a = TimeRangeSet_create();
+        TimeRangeSet_insert(a, range24(0.0, 10.0));
+        TimeRangeSet_insert(a, range24(20.0, 10.0));
+        let b = TimeRangeSet_create();
+        TimeRangeSet_insert(b, range24(5.0, 20.0));
+
+        let union = TimeRangeSet_union(a, b);
+        assert_eq!(ranges(union), [(0.0, 30.0)]);
+        let intersection = TimeRangeSet_intersection(a, b);
+        assert_eq!(ranges(intersection), [(5.0, 5.0), (20.0, 5.0)]);
+        let difference = TimeRangeSet_difference(a, b);
+        assert_eq!(ranges(difference), [(0.0, 5.0), (25.0, 5.0)]);
+        let gaps = TimeRangeSet_complement_within(a, range24(0.0, 40.0));
+        assert_eq!(ranges(gaps), [(10.0, 10.0), (30.0, 10.0)]);
+
+        let copy = TimeRangeSet_clone(a);
+        assert!(TimeRangeSet_equal(a, copy));
+        assert!(!TimeRangeSet_equal(a, b));
+        for set in [a, b, union, intersection, difference, gaps, copy] {
+            TimeRangeSet_destroy(set);
+        }
+    }
+}
+
+#[test]
+fn frame_steps_must_be_positive() {
+    unsafe {
+        for step in [0, -1] {
+            let iter =
+                TimeRange_frames_step_by(range24(0.0, 10.0), 24.0, step, false, ptr::null_mut());
+            assert!(iter.is_null());
+        }
+        let iter = TimeRange_frames_step_by(range24(0.0, 10.0), 24.0, 3, true, ptr::null_mut());
+        assert_eq!(TimeRangeFrameIterator_remaining(iter), 4);
+        let mut frame_time = time(0.0, 0.0);
+        assert!(TimeRangeFrameIterator_next(iter, &mut frame_time));
+        assert_eq!(frame_time.value, 9.0);
+        TimeRangeFrameIterator_destroy(iter);
+    }
+}
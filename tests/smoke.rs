@@ -0,0 +1,1191 @@
+//! End-to-end checks against the native libraries. These only build when
+//! `build.rs` found OpenTimelineIO and compiled the shim.
+#![cfg(otio_native)]
+
+use opentimelineio_bind::*;
+use std::ffi::CString;
+use std::ptr;
+
+fn range(start: f64, duration: f64) -> TimeRange {
+    TimeRange {
+        start_time: RationalTime {
+            value: start,
+            rate: 24.0,
+        },
+        duration: RationalTime {
+            value: duration,
+            rate: 24.0,
+        },
+    }
+}
+
+unsafe fn clip(name: &str, duration: f64) -> *mut Clip {
+    let name = CString::new(name).unwrap();
+    Clip_create(
+        name.as_ptr(),
+        ptr::null_mut(),
+        OptionalTimeRange_create(range(0.0, duration)),
+        AnyDictionary_create(),
+    )
+}
+
+unsafe fn track(clips: &[*mut Clip]) -> *mut Track {
+    let name = CString::new("V1").unwrap();
+    let kind = CString::new("Video").unwrap();
+    let track = Track_create(
+        name.as_ptr(),
+        OptionalTimeRange_create_null(),
+        kind.as_ptr(),
+        AnyDictionary_create(),
+    );
+    let error_status = OTIOErrorStatus_create();
+    for &clip in clips {
+        assert!(Track_append_child(
+            track,
+            clip as *mut Composable,
+            error_status
+        ));
+    }
+    OTIOErrorStatus_destroy(error_status);
+    track
+}
+
+unsafe fn timeline(tracks: &[*mut Track]) -> *mut Timeline {
+    let name = CString::new("edit").unwrap();
+    let timeline = Timeline_create(
+        name.as_ptr(),
+        OptionalRationalTime_create_null(),
+        AnyDictionary_create(),
+    );
+    let error_status = OTIOErrorStatus_create();
+    for &track in tracks {
+        assert!(Stack_append_child(
+            Timeline_tracks(timeline),
+            track as *mut Composable,
+            error_status
+        ));
+    }
+    OTIOErrorStatus_destroy(error_status);
+    timeline
+}
+
+unsafe fn from_json<T>(json: &str) -> *mut T {
+    let json = CString::new(json).unwrap();
+    let error_status = OTIOErrorStatus_create();
+    let object = SerializableObject_from_json_string(json.as_ptr(), error_status);
+    assert_eq!(OTIOErrorStatus_get_outcome(error_status), 0);
+    OTIOErrorStatus_destroy(error_status);
+    object as *mut T
+}
+
+fn retimed_clip_json(time_scalar: f64) -> String {
+    format!(
+        r#"{{
+            "OTIO_SCHEMA": "Clip.1",
+            "name": "retimed",
+            "source_range": {{
+                "OTIO_SCHEMA": "TimeRange.1",
+                "start_time": {{"OTIO_SCHEMA": "RationalTime.1", "rate": 24, "value": 100}},
+                "duration": {{"OTIO_SCHEMA": "RationalTime.1", "rate": 24, "value": 10}}
+            }},
+            "effects": [{{
+                "OTIO_SCHEMA": "LinearTimeWarp.1",
+                "name": "",
+                "effect_name": "LinearTimeWarp",
+                "time_scalar": {},
+                "metadata": {{}}
+            }}],
+            "markers": [],
+            "metadata": {{}},
+            "media_reference": null
+        }}"#,
+        time_scalar
+    )
+}
+
+unsafe fn marker(name: &str, start: f64) -> *mut Marker {
+    let name = CString::new(name).unwrap();
+    let color = CString::new("RED").unwrap();
+    Marker_create(
+        name.as_ptr(),
+        OptionalTimeRange_create(range(start, 1.0)),
+        color.as_ptr(),
+        AnyDictionary_create(),
+    )
+}
+
+unsafe fn release<T>(object: *mut T) {
+    RetainerSerializableObject_managed_destroy(RetainerSerializableObject_create(
+        object as *mut OTIOSerializableObject,
+    ));
+}
+
+#[test]
+fn each_clip_skips_disabled_clips() {
+    unsafe {
+        let first = clip("first", 24.0);
+        let second = clip("second", 24.0);
+        let track = track(&[first, second]);
+        let nested = self::track(&[clip("nested", 24.0)]);
+        let error_status = OTIOErrorStatus_create();
+        assert!(Track_append_child(
+            track,
+            nested as *mut Composable,
+            error_status
+        ));
+        OTIOErrorStatus_destroy(error_status);
+
+        assert!(Clip_enabled(second));
+        Clip_set_enabled(second, false);
+        assert!(!Clip_enabled(second));
+
+        let direct = Track_each_clip(track);
+        let all = Track_each_clip_with_options(track, false);
+        let enabled = Track_each_clip_with_options(track, true);
+        assert_eq!(ComposableVector_size(all), ComposableVector_size(direct));
+        assert_eq!(ComposableVector_size(all), 2);
+        assert_eq!(ComposableVector_size(enabled), 1);
+        assert_eq!(ComposableVector_at(enabled, 0), first as *mut Composable);
+        ComposableVector_destroy(direct);
+        ComposableVector_destroy(all);
+        ComposableVector_destroy(enabled);
+        release(track);
+    }
+}
+
+#[test]
+fn search_finds_clips_lazily_within_range() {
+    unsafe {
+        let first = clip("first", 24.0);
+        let second = clip("second", 24.0);
+        let track = track(&[first, second]);
+
+        let clips: Vec<_> = search::find_clips(&*track, None)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(clips, vec![first, second]);
+
+        let late: Vec<_> = search::find_children::<Clip, _>(&*track, Some(range(30.0, 5.0)), false)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(late, vec![second]);
+
+        let mut calls = 0;
+        let named: Vec<_> = search::find_if(&*track, None, true, |child| {
+            calls += 1;
+            child == second as *mut Composable
+        })
+        .collect::<Result<_, _>>()
+        .unwrap();
+        assert_eq!(named, vec![second as *mut Composable]);
+        assert_eq!(calls, 2);
+
+        let mut panicking = search::find_if(&*track, None, false, |_| panic!("predicate"));
+        assert!(panicking.next().unwrap().is_err());
+        assert!(panicking.next().is_none());
+        drop(panicking);
+        release(track);
+    }
+}
+
+#[test]
+fn items_at_time_are_top_down_with_media_time() {
+    unsafe {
+        let lower = clip("lower", 48.0);
+        let upper = clip("upper", 24.0);
+        let lower_track = track(&[lower]);
+        let upper_track = track(&[upper]);
+        let timeline = timeline(&[lower_track, upper_track]);
+        let error_status = OTIOErrorStatus_create();
+
+        let time = RationalTime {
+            value: 12.0,
+            rate: 24.0,
+        };
+        let items = timeline_items_at_time(timeline, time, false, error_status);
+        assert_eq!(ItemAtTimeVector_size(items), 2);
+        let top = ItemAtTimeVector_at(items, 0);
+        assert_eq!(top.item, upper as *mut Item);
+        assert_eq!(top.parent, upper_track as *mut Composition);
+        assert_eq!(top.item_time.value, 12.0);
+        assert!(top.media_time.valid);
+        assert_eq!(ItemAtTimeVector_at(items, 1).item, lower as *mut Item);
+        ItemAtTimeVector_destroy(items);
+
+        let late = RationalTime {
+            value: 30.0,
+            rate: 24.0,
+        };
+        let items = timeline_items_at_time(timeline, late, false, error_status);
+        assert_eq!(ItemAtTimeVector_size(items), 1);
+        ItemAtTimeVector_destroy(items);
+
+        OTIOErrorStatus_destroy(error_status);
+        release(timeline);
+    }
+}
+
+#[test]
+fn timeline_index_answers_point_and_range_queries() {
+    unsafe {
+        let first = clip("first", 24.0);
+        let second = clip("second", 24.0);
+        let track = track(&[first, second]);
+        let timeline = timeline(&[track]);
+        let error_status = OTIOErrorStatus_create();
+
+        let index = TimelineIndex_create(timeline, error_status);
+        assert_eq!(TimelineIndex_size(index), 2);
+
+        let time = RationalTime {
+            value: 30.0,
+            rate: 24.0,
+        };
+        let found = TimelineIndex_items_at_time(index, time);
+        assert_eq!(IndexedItemVector_size(found), 1);
+        let indexed = IndexedItemVector_at(found, 0);
+        assert_eq!(indexed.item, second as *mut Item);
+        assert_eq!(indexed.track, track);
+        assert_eq!(indexed.range.start_time.value, 24.0);
+        IndexedItemVector_destroy(found);
+
+        let found = TimelineIndex_items_overlapping_range(index, range(20.0, 10.0));
+        assert_eq!(IndexedItemVector_size(found), 2);
+        assert_eq!(IndexedItemVector_at(found, 0).item, first as *mut Item);
+        IndexedItemVector_destroy(found);
+
+        assert!(Track_remove_child(track, 1, error_status));
+        assert_eq!(TimelineIndex_size(index), 2);
+        assert!(TimelineIndex_rebuild(index, error_status));
+        assert_eq!(TimelineIndex_size(index), 1);
+
+        TimelineIndex_destroy(index);
+        OTIOErrorStatus_destroy(error_status);
+        release(timeline);
+    }
+}
+
+#[test]
+fn time_warps_map_parent_time_to_media_time() {
+    unsafe {
+        let error_status = OTIOErrorStatus_create();
+        let at = |value| RationalTime { value, rate: 24.0 };
+
+        let fast: *mut Clip = from_json(&retimed_clip_json(2.0));
+        assert_eq!(
+            Clip_media_time_for_parent_time(fast, at(3.0), error_status).value,
+            106.0
+        );
+        let parent = Clip_parent_time_for_media_time(fast, at(106.0), error_status);
+        assert!(parent.valid);
+        assert_eq!(parent.value.value, 3.0);
+        assert_eq!(
+            Clip_consumed_media_range(fast, error_status).duration.value,
+            20.0
+        );
+
+        let reversed: *mut Clip = from_json(&retimed_clip_json(-1.0));
+        assert_eq!(
+            Clip_media_time_for_parent_time(reversed, at(0.0), error_status).value,
+            109.0
+        );
+        assert_eq!(
+            Clip_media_time_for_parent_time(reversed, at(9.0), error_status).value,
+            100.0
+        );
+
+        let backwards_fast: *mut Clip = from_json(&retimed_clip_json(-2.0));
+        assert_eq!(
+            Clip_consumed_media_range(backwards_fast, error_status)
+                .duration
+                .value,
+            20.0
+        );
+        assert_eq!(
+            Clip_media_time_for_parent_time(backwards_fast, at(0.0), error_status).value,
+            119.0
+        );
+        assert_eq!(
+            Clip_media_time_for_parent_time(backwards_fast, at(9.0), error_status).value,
+            101.0
+        );
+        let parent = Clip_parent_time_for_media_time(backwards_fast, at(101.0), error_status);
+        assert!(parent.valid);
+        assert_eq!(parent.value.value, 9.0);
+
+        let frozen: *mut Clip = from_json(&retimed_clip_json(0.0));
+        assert_eq!(
+            Clip_media_time_for_parent_time(frozen, at(7.0), error_status).value,
+            100.0
+        );
+        assert!(!Clip_parent_time_for_media_time(frozen, at(100.0), error_status).valid);
+
+        assert_eq!(OTIOErrorStatus_get_outcome(error_status), 0);
+        OTIOErrorStatus_destroy(error_status);
+        release(fast);
+        release(reversed);
+        release(backwards_fast);
+        release(frozen);
+    }
+}
+
+#[test]
+fn item_effects_and_markers_are_mutable() {
+    unsafe {
+        let clip = clip("shot", 48.0);
+        let error_status = OTIOErrorStatus_create();
+        let effect = |name: &str| {
+            let name = CString::new(name).unwrap();
+            Effect_create(name.as_ptr(), name.as_ptr(), AnyDictionary_create())
+        };
+
+        let blur = effect("blur");
+        let grade = effect("grade");
+        assert!(Clip_append_effect(clip, blur, error_status));
+        assert!(Clip_insert_effect(clip, 0, grade, error_status));
+        assert!(Clip_move_effect(clip, 0, 1, error_status));
+        let removed = Clip_remove_effect(clip, 1, error_status);
+        assert_eq!(removed, grade);
+        assert!(Clip_remove_effect(clip, 5, error_status).is_null());
+        assert_eq!(
+            OTIOErrorStatus_get_outcome(error_status),
+            OTIO_ErrorStatus_Outcome__OTIO_ErrorStatus_Outcome_ILLEGAL_INDEX as i32
+        );
+        release(removed);
+
+        let late = marker("late", 30.0);
+        let early = marker("early", 10.0);
+        assert!(Clip_append_marker(clip, late, error_status));
+        assert!(Clip_append_marker(clip, early, error_status));
+        Clip_sort_markers(clip);
+        let markers = Clip_markers(clip);
+        assert_eq!(MarkerRetainerVector_size(markers), 2);
+        MarkerRetainerVector_destroy(markers);
+
+        extern "C" fn is_early(marker: *mut Marker, _: *mut std::os::raw::c_void) -> bool {
+            unsafe { Marker_marked_range(marker).start_time.value < 20.0 }
+        }
+        assert_eq!(
+            Clip_retain_markers(clip, Some(is_early), ptr::null_mut()),
+            1
+        );
+
+        OTIOErrorStatus_destroy(error_status);
+        release(clip);
+    }
+}
+
+#[test]
+fn all_markers_are_reported_in_global_time() {
+    unsafe {
+        let first = clip("first", 24.0);
+        let second = clip("second", 24.0);
+        let track = track(&[first, second]);
+        let timeline = timeline(&[track]);
+        let error_status = OTIOErrorStatus_create();
+        Timeline_set_global_start_time(
+            timeline,
+            OptionalRationalTime_create(RationalTime {
+                value: 86400.0,
+                rate: 24.0,
+            }),
+        );
+        assert!(Clip_append_marker(
+            second,
+            marker("note", 5.0),
+            error_status
+        ));
+
+        let markers = timeline_all_markers(timeline, error_status);
+        assert_eq!(MarkerInTimelineVector_size(markers), 1);
+        let found = MarkerInTimelineVector_at(markers, 0);
+        assert_eq!(found.owner, second as *mut Item);
+        assert_eq!(found.global_range.start_time.value, 86400.0 + 24.0 + 5.0);
+        let path = std::ffi::CStr::from_ptr(found.owner_path).to_str().unwrap();
+        assert!(path.ends_with("V1/second"));
+        MarkerInTimelineVector_destroy(markers);
+
+        OTIOErrorStatus_destroy(error_status);
+        release(timeline);
+    }
+}
+
+#[test]
+fn typed_kinds_round_trip_through_the_schema() {
+    use opentimelineio_bind::kinds::*;
+    unsafe {
+        let video = track(&[]);
+        let subtitles = track(&[]);
+        set_track_kind(subtitles, &"Subtitles".parse().unwrap()).unwrap();
+        let timeline = timeline(&[video, subtitles]);
+        assert_eq!(track_kind(video), TrackKind::Video);
+        assert_eq!(track_kind(subtitles), "Subtitles".parse().unwrap());
+        assert!(matches!(track_kind(subtitles), TrackKind::Custom(_)));
+        let video_tracks = Timeline_video_tracks(timeline);
+        assert_eq!(TrackVector_size(video_tracks), 1);
+        TrackVector_destroy(video_tracks);
+
+        let marker = marker("note", 0.0);
+        assert_eq!(marker_color(marker), MarkerColor::Red);
+        set_marker_color(marker, &"TEAL".parse().unwrap()).unwrap();
+        assert!(set_marker_color(marker, &"TE\0AL".parse().unwrap()).is_err());
+        assert_eq!(marker_color(marker).to_string(), "TEAL");
+        release(marker);
+        release(timeline);
+    }
+}
+
+#[test]
+fn normalize_gaps_merges_and_strips_gaps() {
+    unsafe {
+        let error_status = OTIOErrorStatus_create();
+        let gap = |duration: f64| {
+            Gap_create_with_duration(
+                OptionalRationalTime_create(RationalTime {
+                    value: duration,
+                    rate: 24.0,
+                }),
+                ptr::null(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                AnyDictionary_create(),
+            )
+        };
+        let shot = clip("shot", 24.0);
+        let track = track(&[]);
+        let annotated = gap(6.0);
+        assert!(Gap_append_marker(
+            annotated,
+            marker("note", 0.0),
+            error_status
+        ));
+        for child in [
+            gap(12.0) as *mut Composable,
+            shot as *mut Composable,
+            gap(0.0) as *mut Composable,
+            gap(4.0) as *mut Composable,
+            gap(8.0) as *mut Composable,
+            annotated as *mut Composable,
+            gap(2.0) as *mut Composable,
+        ] {
+            assert!(Track_append_child(track, child, error_status));
+        }
+
+        let mut report = GapNormalizationReport {
+            merged_gaps: 0,
+            removed_zero_length_gaps: 0,
+            removed_leading_gaps: 0,
+            removed_trailing_gaps: 0,
+            annotated_gaps_kept: 0,
+        };
+        assert!(track_normalize_gaps(
+            track,
+            true,
+            true,
+            &mut report,
+            error_status
+        ));
+        assert_eq!(report.merged_gaps, 1);
+        assert_eq!(report.removed_zero_length_gaps, 1);
+        assert_eq!(report.removed_leading_gaps, 1);
+        assert_eq!(report.removed_trailing_gaps, 1);
+        assert_eq!(report.annotated_gaps_kept, 1);
+
+        // The shot, the merged 12-frame gap and the annotated gap remain.
+        let children = Track_children(track);
+        assert_eq!(ComposableRetainerVector_size(children), 3);
+        ComposableRetainerVector_destroy(children);
+        assert_eq!(Track_duration(track, error_status).value, 42.0);
+
+        // A transition keeps its zero-length neighbour whether or not it is
+        // annotated, so the annotation kept nothing.
+        let transitioned = self::track(&[]);
+        let noted = gap(0.0);
+        assert!(Gap_append_marker(noted, marker("note", 0.0), error_status));
+        let offset = OptionalRationalTime_create(RationalTime {
+            value: 1.0,
+            rate: 24.0,
+        });
+        let transition = Transition_create(
+            ptr::null(),
+            TransitionType_SMPTE_Dissolve,
+            offset,
+            offset,
+            AnyDictionary_create(),
+        );
+        for child in [
+            clip("a", 24.0) as *mut Composable,
+            noted as *mut Composable,
+            transition as *mut Composable,
+            clip("b", 24.0) as *mut Composable,
+        ] {
+            assert!(Track_append_child(transitioned, child, error_status));
+        }
+        assert!(track_normalize_gaps(
+            transitioned,
+            true,
+            true,
+            &mut report,
+            error_status
+        ));
+        assert_eq!(report.removed_zero_length_gaps, 0);
+        assert_eq!(report.annotated_gaps_kept, 0);
+        let children = Track_children(transitioned);
+        assert_eq!(ComposableRetainerVector_size(children), 4);
+        ComposableRetainerVector_destroy(children);
+
+        OTIOErrorStatus_destroy(error_status);
+        release(track);
+        release(transitioned);
+    }
+}
+
+#[test]
+fn validation_reports_broken_timelines() {
+    let time = |value: f64, rate: f64| {
+        format!(r#"{{"OTIO_SCHEMA": "RationalTime.1", "rate": {rate}, "value": {value}}}"#)
+    };
+    let range = |start: f64, duration: f64, rate: f64| {
+        format!(
+            r#"{{"OTIO_SCHEMA": "TimeRange.1", "start_time": {}, "duration": {}}}"#,
+            time(start, rate),
+            time(duration, rate)
+        )
+    };
+    let json = format!(
+        r#"{{
+            "OTIO_SCHEMA": "Timeline.1",
+            "name": "edit",
+            "global_start_time": null,
+            "metadata": {{}},
+            "tracks": {{
+                "OTIO_SCHEMA": "Stack.1",
+                "name": "tracks",
+                "source_range": null,
+                "effects": [],
+                "markers": [],
+                "metadata": {{}},
+                "children": [{{
+                    "OTIO_SCHEMA": "Track.1",
+                    "name": "V1",
+                    "kind": "Video",
+                    "source_range": null,
+                    "effects": [],
+                    "markers": [],
+                    "metadata": {{}},
+                    "children": [
+                        {{
+                            "OTIO_SCHEMA": "Transition.1",
+                            "name": "dissolve",
+                            "transition_type": "SMPTE_Dissolve",
+                            "in_offset": {in_offset},
+                            "out_offset": {out_offset},
+                            "metadata": {{}}
+                        }},
+                        {{
+                            "OTIO_SCHEMA": "Clip.1",
+                            "name": "a",
+                            "source_range": {a_range},
+                            "media_reference": {{
+                                "OTIO_SCHEMA": "ExternalReference.1",
+                                "name": "",
+                                "target_url": "a.mov",
+                                "available_range": {a_available},
+                                "metadata": {{}}
+                            }},
+                            "effects": [],
+                            "markers": [],
+                            "metadata": {{}}
+                        }},
+                        {{
+                            "OTIO_SCHEMA": "Clip.1",
+                            "name": "b",
+                            "source_range": {b_range},
+                            "media_reference": null,
+                            "effects": [],
+                            "markers": [],
+                            "metadata": {{"vendor": {{"OTIO_SCHEMA": "VendorThing.1", "x": 1}}}}
+                        }}
+                    ]
+                }}]
+            }}
+        }}"#,
+        in_offset = time(12.0, 24.0),
+        out_offset = time(12.0, 24.0),
+        a_range = range(0.0, 24.0, 24.0),
+        a_available = range(0.0, 48.0, 24.0),
+        b_range = range(0.0, 25.0, 25.0),
+    );
+    unsafe {
+        let timeline = from_json::<Timeline>(&json);
+        let error_status = OTIOErrorStatus_create();
+        let diagnostics = validate_timeline(timeline, error_status);
+        assert!(!diagnostics.is_null());
+        let mut found = Vec::new();
+        for index in 0..DiagnosticVector_size(diagnostics) {
+            let diagnostic = DiagnosticVector_at(diagnostics, index);
+            let kind = std::ffi::CStr::from_ptr(Diagnostic_kind_to_string(diagnostic.kind));
+            let path = std::ffi::CStr::from_ptr(diagnostic.object_path);
+            found.push(format!(
+                "{} {}",
+                kind.to_str().unwrap(),
+                path.to_str().unwrap()
+            ));
+        }
+        DiagnosticVector_destroy(diagnostics);
+        found.sort();
+        assert_eq!(
+            found,
+            [
+                "MISSING_MEDIA_REFERENCE tracks/V1/b",
+                "MIXED_FRAME_RATES tracks/V1/b",
+                "TRANSITION_AT_TRACK_EDGE tracks/V1/dissolve",
+                "TRANSITION_EXCEEDS_HANDLES tracks/V1/dissolve",
+                "UNKNOWN_SCHEMA tracks/V1/b/vendor",
+            ]
+        );
+        OTIOErrorStatus_destroy(error_status);
+        release(timeline);
+    }
+}
+
+#[test]
+fn diff_reports_moves_renames_and_trims() {
+    unsafe {
+        let old = timeline(&[track(&[clip("a", 24.0), clip("b", 24.0), clip("c", 24.0)])]);
+        let new = timeline(&[track(&[clip("b", 24.0), clip("a", 12.0), clip("d", 24.0)])]);
+        let error_status = OTIOErrorStatus_create();
+        let differences = diff_timelines(old, new, error_status);
+        assert_eq!(DifferenceVector_size(differences), 2);
+
+        let moved = DifferenceVector_at(differences, 0);
+        assert_eq!(
+            moved.kind,
+            OTIO_Difference_Kind__OTIO_Difference_Kind_MOVED as i32
+        );
+        assert_eq!(
+            moved.changed_fields,
+            OTIO_Difference_Field__OTIO_Difference_Field_SOURCE_RANGE as i32
+        );
+        assert!(std::ffi::CStr::from_ptr(moved.new_path)
+            .to_str()
+            .unwrap()
+            .ends_with("V1/a"));
+
+        let renamed = DifferenceVector_at(differences, 1);
+        assert_eq!(
+            renamed.kind,
+            OTIO_Difference_Kind__OTIO_Difference_Kind_MODIFIED as i32
+        );
+        assert_eq!(
+            renamed.changed_fields,
+            OTIO_Difference_Field__OTIO_Difference_Field_NAME as i32
+        );
+        assert!(std::ffi::CStr::from_ptr(renamed.old_path)
+            .to_str()
+            .unwrap()
+            .ends_with("V1/c"));
+
+        DifferenceVector_destroy(differences);
+
+        // b leaves a track that is removed, which is still a move.
+        let split = timeline(&[track(&[clip("a", 24.0)]), track(&[clip("b", 24.0)])]);
+        let joined = timeline(&[track(&[clip("a", 24.0), clip("b", 24.0)])]);
+        let differences = diff_timelines(split, joined, error_status);
+        let kinds: Vec<_> = (0..DifferenceVector_size(differences))
+            .map(|index| DifferenceVector_at(differences, index).kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                OTIO_Difference_Kind__OTIO_Difference_Kind_MOVED as i32,
+                OTIO_Difference_Kind__OTIO_Difference_Kind_REMOVED as i32,
+            ]
+        );
+        DifferenceVector_destroy(differences);
+
+        assert!(diff_timelines(ptr::null_mut(), new, error_status).is_null());
+        assert_ne!(OTIOErrorStatus_get_outcome(error_status), 0);
+
+        OTIOErrorStatus_destroy(error_status);
+        release(old);
+        release(new);
+        release(split);
+        release(joined);
+    }
+}
+
+#[test]
+fn compare_cuts_lists_editorial_changes() {
+    extern "C" {
+        fn free(ptr: *mut std::os::raw::c_void);
+    }
+    unsafe {
+        let audio = track(&[clip("x", 24.0)]);
+        let audio_kind = CString::new("Audio").unwrap();
+        Track_set_kind(audio, audio_kind.as_ptr());
+        let old = timeline(&[
+            track(&[clip("a", 24.0), clip("b", 24.0), clip("c", 24.0)]),
+            audio,
+        ]);
+        let new = timeline(&[track(&[clip("a", 30.0), clip("c", 24.0), clip("e", 24.0)])]);
+        let error_status = OTIOErrorStatus_create();
+        let audio_changes = compare_cuts(
+            old,
+            new,
+            audio_kind.as_ptr(),
+            OTIO_CutChange_MatchBy__OTIO_CutChange_MatchBy_NAME as i32,
+            ptr::null(),
+            error_status,
+        );
+        assert_eq!(CutChangeList_size(audio_changes), 1);
+        assert_eq!(
+            CutChangeList_at(audio_changes, 0).kind,
+            OTIO_CutChange_Kind__OTIO_CutChange_Kind_OMIT as i32
+        );
+        CutChangeList_destroy(audio_changes);
+
+        // Without a kind only the video tracks are compared.
+        let changes = compare_cuts(
+            old,
+            new,
+            ptr::null(),
+            OTIO_CutChange_MatchBy__OTIO_CutChange_MatchBy_NAME as i32,
+            ptr::null(),
+            error_status,
+        );
+        let kinds: Vec<_> = (0..CutChangeList_size(changes))
+            .map(|index| CutChangeList_at(changes, index).kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                OTIO_CutChange_Kind__OTIO_CutChange_Kind_TRIM as i32,
+                OTIO_CutChange_Kind__OTIO_CutChange_Kind_OMIT as i32,
+                OTIO_CutChange_Kind__OTIO_CutChange_Kind_INSERT as i32,
+            ]
+        );
+        let trim = CutChangeList_at(changes, 0);
+        assert_eq!(trim.head_delta.value, 0.0);
+        assert_eq!(trim.tail_delta.value, 6.0);
+
+        let csv = CutChangeList_to_csv_string(changes, 24.0, error_status);
+        let text = std::ffi::CStr::from_ptr(csv).to_str().unwrap().to_owned();
+        free(csv as *mut std::os::raw::c_void);
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[1],
+            "TRIM,a,a,00:00:00:00,00:00:01:00,00:00:00:00,00:00:01:06,0,6"
+        );
+        assert_eq!(lines[2], "OMIT,b,,00:00:01:00,00:00:02:00,,,0,0");
+
+        CutChangeList_destroy(changes);
+        OTIOErrorStatus_destroy(error_status);
+        release(old);
+        release(new);
+    }
+}
+
+#[test]
+fn merge_combines_edits_on_different_tracks() {
+    unsafe {
+        let base = timeline(&[
+            track(&[clip("a", 24.0), clip("b", 24.0)]),
+            track(&[clip("m", 24.0)]),
+        ]);
+        let ours = timeline(&[
+            track(&[clip("a", 12.0), clip("b", 24.0)]),
+            track(&[clip("m", 24.0)]),
+        ]);
+        let theirs = timeline(&[
+            track(&[clip("a", 24.0), clip("b", 24.0)]),
+            track(&[clip("m", 24.0), clip("n", 24.0)]),
+        ]);
+        let error_status = OTIOErrorStatus_create();
+        let result = merge_timelines(base, ours, theirs, error_status);
+        assert!(!result.is_null());
+        assert!(!MergeResult_has_conflicts(result));
+        let merged = MergeResult_merged_object(result) as *mut Timeline;
+        let mut durations: Vec<_> = search::find_clips(&*merged, None)
+            .map(|clip| Clip_duration(clip.unwrap(), error_status).value)
+            .collect();
+        durations.sort_by(f64::total_cmp);
+        assert_eq!(durations, [12.0, 24.0, 24.0, 24.0]);
+        MergeResult_destroy(result);
+
+        let conflicting = timeline(&[
+            track(&[clip("a", 6.0), clip("b", 24.0)]),
+            track(&[clip("m", 24.0)]),
+        ]);
+        let result = merge_timelines(base, ours, conflicting, error_status);
+        let conflicts = MergeResult_conflicts(result);
+        assert_eq!(MergeConflictVector_size(conflicts), 1);
+        let conflict = MergeConflictVector_at(conflicts, 0);
+        assert_eq!(
+            conflict.kind,
+            OTIO_MergeConflict_Kind__OTIO_MergeConflict_Kind_BOTH_MODIFIED as i32
+        );
+        assert!(std::ffi::CStr::from_ptr(conflict.path)
+            .to_str()
+            .unwrap()
+            .ends_with("V1/a"));
+        MergeConflictVector_destroy(conflicts);
+        MergeResult_destroy(result);
+
+        OTIOErrorStatus_destroy(error_status);
+        for timeline in [base, ours, theirs, conflicting] {
+            release(timeline);
+        }
+    }
+}
+
+#[test]
+fn conform_rate_reports_sub_frame_values() {
+    unsafe {
+        let a = clip("a", 24.0);
+        let b = clip("b", 10.0);
+        let timeline = timeline(&[track(&[a, b])]);
+        let error_status = OTIOErrorStatus_create();
+        let sub_frames = timeline_conform_rate(
+            timeline,
+            25.0,
+            OTIO_RateConform_Mode__OTIO_RateConform_Mode_PRESERVE_REAL_TIME as i32,
+            OpenTime_RoundingPolicy__OpenTime_RoundingPolicy_Floor as i32,
+            error_status,
+        );
+        assert_eq!(Clip_duration(a, error_status).value, 25.0);
+        assert_eq!(Clip_duration(b, error_status).rate, 25.0);
+        assert_eq!(SubFrameValueVector_size(sub_frames), 1);
+        let sub_frame = SubFrameValueVector_at(sub_frames, 0);
+        let field = std::ffi::CStr::from_ptr(sub_frame.field);
+        assert_eq!(field.to_str().unwrap(), "source_range.end_time_exclusive");
+        let path = std::ffi::CStr::from_ptr(sub_frame.path);
+        assert!(path.to_str().unwrap().ends_with("V1/b"));
+        assert_eq!(sub_frame.conformed_value.value, 10.0);
+        SubFrameValueVector_destroy(sub_frames);
+        OTIOErrorStatus_destroy(error_status);
+        release(timeline);
+    }
+}
+
+#[test]
+fn exact_rational_errors_fill_the_status() {
+    unsafe {
+        let error_status = OpenTimeErrorStatus_create();
+        let invalid = ExactRational_create(1, 0, error_status);
+        assert_eq!(invalid.denominator, 0);
+        let rate = ExactRational_create(1, 1, error_status);
+        let sum = ExactRationalTime_add(
+            ExactRationalTime_from_frames(i64::MAX, rate),
+            ExactRationalTime_from_frames(1, rate),
+            error_status,
+        );
+        assert!(ExactRationalTime_is_invalid_time(sum));
+        OpenTimeErrorStatus_destroy(error_status);
+    }
+}
+
+#[test]
+fn pull_list_merges_media_and_clamps_handles() {
+    extern "C" {
+        fn free(ptr: *mut std::os::raw::c_void);
+    }
+    unsafe {
+        let a = clip("a", 24.0);
+        let b = clip("b", 10.0);
+        let c = clip("c", 10.0);
+        let name = CString::new("d").unwrap();
+        let d = Clip_create(
+            name.as_ptr(),
+            ptr::null_mut(),
+            OptionalTimeRange_create(range(1987200.0, 10.0)),
+            AnyDictionary_create(),
+        );
+        for (clip, url, available) in [
+            (a, "a.mov", Some(range(0.0, 100.0))),
+            (b, "b.mov", None),
+            (c, "a.mov", Some(range(0.0, 100.0))),
+            (d, "d.mov", None),
+        ] {
+            let url = CString::new(url).unwrap();
+            let available = match available {
+                Some(available) => OptionalTimeRange_create(available),
+                None => OptionalTimeRange_create_null(),
+            };
+            let reference =
+                ExternalReference_create(url.as_ptr(), available, AnyDictionary_create());
+            Clip_set_media_reference(clip, reference as *mut MediaReference);
+        }
+        let timeline = timeline(&[track(&[a, b, c, d])]);
+        let error_status = OTIOErrorStatus_create();
+        let handle = RationalTime {
+            value: 5.0,
+            rate: 24.0,
+        };
+        let pulls = timeline_pull_list(timeline, handle, handle, true, error_status);
+        assert_eq!(PullList_size(pulls), 3);
+        let first = PullList_at(pulls, 0);
+        assert_eq!(
+            std::ffi::CStr::from_ptr(first.target_url).to_str(),
+            Ok("a.mov")
+        );
+        assert_eq!(first.clip_count, 2);
+        assert!(first.handles_clamped);
+        assert_eq!(first.media_range.start_time.value, 0.0);
+        assert_eq!(first.media_range.duration.value, 29.0);
+        let second = PullList_at(pulls, 1);
+        assert!(!second.handles_clamped);
+        assert_eq!(second.media_range.start_time.value, -5.0);
+
+        let csv = PullList_to_csv_string(pulls, error_status);
+        let text = std::ffi::CStr::from_ptr(csv).to_str().unwrap().to_owned();
+        assert!(text.contains("\na.mov,0,29,29,24,2,true\n"));
+        assert!(text.contains("\nd.mov,1987195,1987215,20,24,1,false\n"));
+        free(csv as *mut std::os::raw::c_void);
+
+        PullList_destroy(pulls);
+        OTIOErrorStatus_destroy(error_status);
+        release(timeline);
+    }
+}
+
+#[test]
+fn relink_keeps_links_and_reports_missing_media() {
+    use opentimelineio_bind::relink::{Link, Linker, MediaLinker};
+
+    struct Remap {
+        seen: Vec<Option<String>>,
+    }
+
+    impl MediaLinker for Remap {
+        fn name(&self) -> &str {
+            "remap"
+        }
+
+        fn link(&mut self, _: &str, _: *mut AnyDictionary, url: Option<&str>) -> Link {
+            self.seen.push(url.map(str::to_owned));
+            match url {
+                Some("old/a.mov") => {
+                    let url = CString::new("new/a.mov").unwrap();
+                    Link::Linked(unsafe {
+                        ExternalReference_create(
+                            url.as_ptr(),
+                            OptionalTimeRange_create_null(),
+                            AnyDictionary_create(),
+                        ) as *mut MediaReference
+                    })
+                }
+                Some(_) => Link::Unresolved,
+                None => Link::Keep,
+            }
+        }
+    }
+
+    unsafe {
+        let a = clip("a", 10.0);
+        let b = clip("b", 10.0);
+        let c = clip("c", 10.0);
+        for (clip, url) in [(a, "old/a.mov"), (b, "old/b.mov")] {
+            let url = CString::new(url).unwrap();
+            let reference = ExternalReference_create(
+                url.as_ptr(),
+                OptionalTimeRange_create_null(),
+                AnyDictionary_create(),
+            );
+            Clip_set_media_reference(clip, reference as *mut MediaReference);
+        }
+        let timeline = timeline(&[track(&[a, b, c])]);
+        let mut remap = Remap { seen: Vec::new() };
+        let report = {
+            let mut linker = Linker::new(&mut remap).unwrap();
+            assert_eq!(linker.name(), "remap");
+            linker.relink(timeline, true).unwrap()
+        };
+        assert_eq!(
+            (report.relinked, report.missing, report.unchanged),
+            (1, 1, 1)
+        );
+        assert_eq!(remap.seen[0].as_deref(), Some("old/a.mov"));
+        let relinked = Clip_media_reference(a) as *mut ExternalReference;
+        assert_eq!(
+            std::ffi::CStr::from_ptr(ExternalReference_target_url(relinked)).to_str(),
+            Ok("new/a.mov")
+        );
+        let missing = std::ffi::CStr::from_ptr(MediaReference_schema_name(Clip_media_reference(b)));
+        assert_eq!(missing.to_str(), Ok("MissingReference"));
+
+        let mut prefix = Linker::path_prefix_remap("new/", "/mnt/").unwrap();
+        let report = prefix.relink(timeline, false).unwrap();
+        assert_eq!((report.relinked, report.unchanged), (1, 2));
+        release(timeline);
+    }
+}
+
+#[test]
+fn directory_search_decodes_and_encodes_file_urls() {
+    use opentimelineio_bind::relink::Linker;
+
+    unsafe {
+        let root = std::env::temp_dir().join(format!("relink-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("day 1")).unwrap();
+        std::fs::write(root.join("day 1").join("d #1.mov"), b"").unwrap();
+        let d = clip("d", 10.0);
+        let url = CString::new("file:///old/d%20%231.mov").unwrap();
+        let reference = ExternalReference_create(
+            url.as_ptr(),
+            OptionalTimeRange_create_null(),
+            AnyDictionary_create(),
+        );
+        Clip_set_media_reference(d, reference as *mut MediaReference);
+        let timeline = timeline(&[track(&[d])]);
+        let mut search = Linker::directory_search(root.to_str().unwrap(), true).unwrap();
+        let report = search.relink(timeline, false).unwrap();
+        assert_eq!(report.relinked, 1);
+        let relinked = Clip_media_reference(d) as *mut ExternalReference;
+        let url = std::ffi::CStr::from_ptr(ExternalReference_target_url(relinked));
+        assert!(url.to_str().unwrap().starts_with("file:///"));
+        assert!(url.to_str().unwrap().ends_with("/day%201/d%20%231.mov"));
+        assert!(Linker::directory_search("a\0b", false).is_err());
+        release(timeline);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
+
+#[test]
+fn image_sequence_reference_maps_frames_to_urls() {
+    extern "C" {
+        fn free(ptr: *mut std::os::raw::c_void);
+    }
+    unsafe {
+        let base = CString::new("file:///shots/").unwrap();
+        let prefix = CString::new("sh010.").unwrap();
+        let suffix = CString::new(".exr").unwrap();
+        let reference = ImageSequenceReference_create(
+            base.as_ptr(),
+            prefix.as_ptr(),
+            suffix.as_ptr(),
+            1,
+            1,
+            24.0,
+            4,
+            OTIO_ImageSequenceReference_MissingFramePolicy__OTIO_ImageSequenceReference_MissingFramePolicy_hold
+                as OTIO_ImageSequenceReference_MissingFramePolicy,
+            OptionalTimeRange_create(range(0.0, 48.0)),
+            AnyDictionary_create(),
+        );
+        assert_eq!(
+            ImageSequenceReference_number_of_images_in_sequence(reference),
+            48
+        );
+        assert_eq!(ImageSequenceReference_end_frame(reference), 48);
+        assert_eq!(ImageSequenceReference_frame_zero_padding(reference), 4);
+        assert_eq!(ImageSequenceReference_missing_frame_policy(reference), 1);
+
+        let error_status = OTIOErrorStatus_create();
+        let time = RationalTime {
+            value: 12.0,
+            rate: 24.0,
+        };
+        assert_eq!(
+            ImageSequenceReference_frame_for_time(reference, time, error_status),
+            13
+        );
+        let url = ImageSequenceReference_target_url_for_image_number(reference, 0, error_status);
+        assert_eq!(
+            std::ffi::CStr::from_ptr(url).to_str(),
+            Ok("file:///shots/sh010.0001.exr")
+        );
+        free(url as *mut std::os::raw::c_void);
+        let presentation =
+            ImageSequenceReference_presentation_time_for_image_number(reference, 2, error_status);
+        assert_eq!(presentation.value, 2.0);
+        assert_eq!(OTIOErrorStatus_get_outcome(error_status), 0);
+
+        let new_base = CString::new("file:///renders/").unwrap();
+        ImageSequenceReference_set_target_url_base(reference, new_base.as_ptr());
+        let url = ImageSequenceReference_target_url_base(reference);
+        assert_eq!(
+            std::ffi::CStr::from_ptr(url).to_str(),
+            Ok("file:///renders/")
+        );
+        free(url as *mut std::os::raw::c_void);
+        let schema = std::ffi::CStr::from_ptr(ImageSequenceReference_schema_name(reference));
+        assert_eq!(schema.to_str(), Ok("ImageSequenceReference"));
+
+        OTIOErrorStatus_destroy(error_status);
+        release(reference);
+    }
+}
+
+#[test]
+fn clips_hold_media_references_by_key() {
+    extern "C" {
+        fn free(ptr: *mut std::os::raw::c_void);
+    }
+    unsafe fn key_string(key: *const std::os::raw::c_char) -> String {
+        let string = std::ffi::CStr::from_ptr(key).to_str().unwrap().to_owned();
+        free(key as *mut std::os::raw::c_void);
+        string
+    }
+    unsafe {
+        let clip = clip("a", 10.0);
+        let default_key = std::ffi::CStr::from_ptr(Clip_default_media_key);
+        assert_eq!(
+            key_string(Clip_active_media_reference_key(clip)).as_str(),
+            default_key.to_str().unwrap()
+        );
+
+        let error_status = OTIOErrorStatus_create();
+        let proxy_key = CString::new("proxy").unwrap();
+        let url = CString::new("proxy.mov").unwrap();
+        let proxy = ExternalReference_create(
+            url.as_ptr(),
+            OptionalTimeRange_create_null(),
+            AnyDictionary_create(),
+        ) as *mut MediaReference;
+        assert!(Clip_set_media_reference_for_key(
+            clip,
+            proxy_key.as_ptr(),
+            proxy,
+            error_status
+        ));
+        assert_eq!(Clip_media_reference_count(clip), 2);
+        assert_eq!(key_string(Clip_media_reference_key_at(clip, 1)), "proxy");
+        assert!(Clip_media_reference_key_at(clip, 2).is_null());
+        assert_eq!(
+            Clip_media_reference_for_key(clip, proxy_key.as_ptr()),
+            proxy
+        );
+
+        assert!(Clip_set_active_media_reference_key(
+            clip,
+            proxy_key.as_ptr(),
+            error_status
+        ));
+        assert_eq!(Clip_media_reference(clip), proxy);
+        assert!(!Clip_remove_media_reference(
+            clip,
+            proxy_key.as_ptr(),
+            error_status
+        ));
+        assert_ne!(OTIOErrorStatus_get_outcome(error_status), 0);
+        OTIOErrorStatus_destroy(error_status);
+
+        let error_status = OTIOErrorStatus_create();
+        assert!(Clip_remove_media_reference(
+            clip,
+            Clip_default_media_key,
+            error_status
+        ));
+        assert_eq!(Clip_media_reference_count(clip), 1);
+        let missing = CString::new("missing").unwrap();
+        assert!(!Clip_remove_media_reference(
+            clip,
+            missing.as_ptr(),
+            error_status
+        ));
+        OTIOErrorStatus_destroy(error_status);
+        release(clip);
+    }
+}